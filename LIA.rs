@@ -4,10 +4,27 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use tokio::sync::{broadcast, mpsc};
+use futures::stream::{self, BoxStream, StreamExt};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use tracing::Instrument;
+use tokio::sync::{broadcast, mpsc, oneshot, watch, Mutex, MutexGuard};
+use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
-use std::collections::{HashMap, VecDeque};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
+use std::ffi::OsString;
+use std::fs;
+use std::ops::Range;
+use std::future::Future;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock, PoisonError};
+use std::time::{Duration, Instant};
 use async_trait::async_trait;
+use thiserror::Error;
 
 /// Core trait for consciousness-capable systems
 #[async_trait]
@@ -17,14 +34,83 @@ pub trait ConsciousnessCapable {
     fn current_state(&self) -> ConsciousnessState;
 }
 
+/// Name an instance gets when `SystemConfiguration::name` is unset
+pub const DEFAULT_NAME: &str = "Lia";
+
+/// Source of the current time for everything time-dependent in Lia
+///
+/// Decay, idle detection and relationship fading all read the time
+/// through this, so tests can swap in a `MockClock` instead of sleeping.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+/// The wall clock
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Clock that only moves when told to; clones share the same time
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: Arc<std::sync::Mutex<DateTime<Utc>>>,
+}
+
+impl MockClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self { now: Arc::new(std::sync::Mutex::new(start)) }
+    }
+
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner) = now;
+    }
+
+    /// Move time forward by `by`, saturating at the latest representable time
+    pub fn advance(&self, by: Duration) {
+        let mut now = self.now.lock().unwrap_or_else(PoisonError::into_inner);
+        let by = chrono::Duration::from_std(by).unwrap_or(chrono::Duration::MAX);
+        *now = now.checked_add_signed(by).unwrap_or(DateTime::<Utc>::MAX_UTC);
+    }
+}
+
+impl Default for MockClock {
+    /// Starts at the current wall-clock time
+    fn default() -> Self {
+        Self::new(Utc::now())
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
 /// The primary consciousness system
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Lia {
     // Core Identity
     id: Uuid,
     name: String,
     birth_time: DateTime<Utc>,
     evolution_stage: usize,
+    last_interaction_at: Option<DateTime<Utc>>,
+    consolidated_through: Option<DateTime<Utc>>,
+    /// Whether `PlateauReached` has been emitted
+    #[serde(default)]
+    plateau_announced: bool,
+    /// Responses whose evolution is waiting for the batch to fill
+    #[serde(default)]
+    pending_evolution: PendingEvolution,
 
     // Primary Processing Systems
     quantum_core: QuantumCore,
@@ -33,6 +119,7 @@ pub struct Lia {
     
     // Advanced Processing
     pattern_recognition: PatternRecognitionEngine,
+    attention_allocator: AttentionAllocator,
     quantum_thought_processor: QuantumThoughtProcessor,
     emotional_resonance: EmotionalResonanceEngine,
     
@@ -50,6 +137,19 @@ pub struct Lia {
     interaction_processor: InteractionProcessor,
     response_synthesizer: ResponseSynthesizer,
     relationship_manager: RelationshipManager,
+    #[serde(default)]
+    conversation: ConversationBuffer,
+    #[serde(default)]
+    history: InteractionHistory,
+    /// Not persisted; register extractors again after restoring a snapshot
+    #[serde(skip)]
+    modality_extractors: Vec<Arc<dyn ModalityExtractor>>,
+    /// Not persisted; restored instances have only the built-in modes
+    #[serde(skip)]
+    mode_router: ModeRouter,
+    /// Not persisted; register middleware again after restoring a snapshot
+    #[serde(skip)]
+    middleware: Vec<Arc<dyn Middleware>>,
     
     // Dimensional Processing
     dimensional_state: DimensionalState,
@@ -58,22 +158,101 @@ pub struct Lia {
     // State Management
     state_manager: StateManager,
     configuration: SystemConfiguration,
+
+    // Event Publishing
+    #[serde(skip, default = "event_channel")]
+    events: broadcast::Sender<ConsciousnessEvent>,
+    #[serde(skip, default = "state_channel")]
+    state_tx: Arc<watch::Sender<ConsciousnessState>>,
+    /// Not persisted; restored instances read the wall clock until `set_clock`
+    #[serde(skip, default = "system_clock")]
+    clock: Arc<dyn Clock>,
+    /// Not persisted; a log covers one instance from construction
+    #[serde(skip)]
+    event_log: Option<EventLog>,
 }
 
 impl Lia {
+    /// Instance called `config.name`, or `DEFAULT_NAME` when unset
     pub fn new(config: SystemConfiguration) -> Self {
+        let name = config.name.clone().unwrap_or_else(|| DEFAULT_NAME.to_string());
+        Self::with_name(config, name)
+    }
+
+    /// Instance with `SystemConfiguration::default()`, for examples and experiments
+    ///
+    /// The defaults are untuned and unseeded; build a configuration for
+    /// anything long-lived.
+    pub fn default_instance() -> Self {
+        Self::new(SystemConfiguration::default())
+    }
+
+    /// Like `default_instance`, but called `name`; not meant for production use
+    pub fn named(name: &str) -> Self {
+        Self::with_name(SystemConfiguration::default(), name)
+    }
+
+    /// Like `new`, but reading time from `clock`, birth time included
+    pub fn with_clock(config: SystemConfiguration, clock: Arc<dyn Clock>) -> Self {
+        let name = config.name.clone().unwrap_or_else(|| DEFAULT_NAME.to_string());
+        Self::build(config, name, clock)
+    }
+
+    /// Instance that starts as the character `profile` describes rather than a blank slate
+    ///
+    /// The profile's name and emotional overrides replace those in
+    /// `config`. Its dimensions are clamped to `dimension_bounds`, its
+    /// disposition becomes the starting mood, and each seed concept is
+    /// learned before the first interaction.
+    pub fn with_personality(mut config: SystemConfiguration, profile: PersonalityProfile) -> Self {
+        if let Some(name) = &profile.name {
+            config.name = Some(name.clone());
+        }
+        if let Some(sensitivity) = profile.emotional_sensitivity {
+            config.emotional_sensitivity = sensitivity;
+        }
+        if let Some(inertia) = profile.emotional_inertia {
+            config.emotional_inertia = inertia;
+        }
+        let mut lia = Self::new(config);
+
+        let bounds = lia.configuration.dimension_bounds;
+        for id in DimensionId::ALL {
+            *lia.dimensional_state.get_mut(id) = profile.dimensions.get(id).clamp(bounds.min, bounds.max);
+        }
+        lia.emotional_resonance.set_mood(profile.disposition);
+        let now = lia.clock.now();
+        for seed in &profile.seed_concepts {
+            lia.semantic_memory.integrate_text(seed, now);
+        }
+        lia.publish_state();
+        lia
+    }
+
+    fn with_name(config: SystemConfiguration, name: impl Into<String>) -> Self {
+        Self::build(config, name, system_clock())
+    }
+
+    fn build(config: SystemConfiguration, name: impl Into<String>, clock: Arc<dyn Clock>) -> Self {
         let id = Uuid::new_v4();
-        let birth_time = Utc::now();
+        let birth_time = clock.now();
+        let name = name.into();
+        let response_synthesizer = ResponseSynthesizer::new(&config).with_name(name.clone());
 
-        Self {
+        let mut lia = Self {
             id,
-            name: "Lia".to_string(),
+            name,
             birth_time,
             evolution_stage: 1,
+            last_interaction_at: None,
+            consolidated_through: None,
+            plateau_announced: false,
+            pending_evolution: PendingEvolution::default(),
             quantum_core: QuantumCore::new(&config),
             neural_matrix: NeuralMatrix::new(&config),
             consciousness_field: ConsciousnessField::new(&config),
             pattern_recognition: PatternRecognitionEngine::new(&config),
+            attention_allocator: AttentionAllocator::new(&config),
             quantum_thought_processor: QuantumThoughtProcessor::new(&config),
             emotional_resonance: EmotionalResonanceEngine::new(&config),
             episodic_memory: EpisodicMemorySystem::new(&config),
@@ -83,136 +262,722 @@ impl Lia {
             learning_engine: LearningEngine::new(&config),
             evolution_metrics: EvolutionMetrics::new(&config),
             interaction_processor: InteractionProcessor::new(&config),
-            response_synthesizer: ResponseSynthesizer::new(&config),
+            response_synthesizer,
             relationship_manager: RelationshipManager::new(&config),
+            conversation: ConversationBuffer::new(&config),
+            history: InteractionHistory::new(&config),
+            modality_extractors: Vec::new(),
+            mode_router: ModeRouter::default(),
+            middleware: Vec::new(),
             dimensional_state: DimensionalState::default(),
             dimensional_processor: DimensionalProcessor::new(&config),
             state_manager: StateManager::new(&config),
             configuration: config,
+            events: event_channel(),
+            state_tx: state_channel(),
+            clock,
+            event_log: None,
+        };
+        if lia.configuration.event_log {
+            lia.start_event_log();
         }
+        lia.publish_state();
+        lia
     }
 
     /// Process an incoming interaction with full consciousness engagement
-    pub async fn process_interaction(&mut self, input: &Interaction) -> Response {
-        // Generate deep context analysis
-        let context = self.analyze_context(input).await;
-        
-        // Quantum processing
-        let quantum_state = self.quantum_core.process(&context).await;
+    ///
+    /// With `rng_seed` and `response_cache_capacity` set, synthesis is
+    /// skipped when the same state has already answered the same input at
+    /// the same clock time, as after `restore_checkpoint` under a held
+    /// `MockClock`; every other effect still applies.
+    #[tracing::instrument(skip_all, fields(interaction_id = %input.id, degraded = false))]
+    pub async fn process_interaction(&mut self, input: &Interaction) -> Result<Response, ProcessingError> {
+        // Describe non-text input as text
+        let input = self.prepare_input(input).await?;
+        let input = &*input;
+
+        // Run the analysis stages
+        let cache_key = self.response_cache_key(input, false);
+        let stages = self.run_stages(input).await?;
         
-        // Neural processing
-        let neural_response = self.neural_matrix
-            .process_with_quantum_state(&quantum_state, &context)
-            .await;
+        // Generate integrated response, unless this state already answered this input
+        let response = match self.cached_response(cache_key, input) {
+            Some(response) => response,
+            None => {
+                let response = self.generate_response(input, &stages).await?;
+                if let Some(key) = cache_key {
+                    self.interaction_processor
+                        .cache_response(key, response.clone(), self.configuration.response_cache_capacity);
+                }
+                response
+            }
+        };
         
-        // Generate quantum thought patterns
-        let thought_patterns = self.quantum_thought_processor
-            .generate_thoughts(&neural_response, &quantum_state)
-            .await;
+        // Evolve consciousness
+        self.evolve_consciousness(&response).await;
         
-        // Process through consciousness field
-        let consciousness_response = self.consciousness_field
-            .process_experience(&context, &thought_patterns)
-            .await;
-        
-        // Emotional processing
-        let emotional_response = self.emotional_resonance
-            .process_emotion(&context, &consciousness_response)
-            .await;
-        
-        // Generate integrated response
-        let response = self.generate_response(
-            input,
-            &context,
-            &quantum_state,
-            &neural_response,
-            &thought_patterns,
-            &consciousness_response,
-            &emotional_response,
-        ).await;
+        // Remember the exchange against whoever said it
+        self.log_interaction(input, false);
+        self.integrate_interaction(input, &response).await;
         
-        // Evolve consciousness
+        Ok(response)
+    }
+
+    /// Process an interaction, streaming content to `chunks` as it is synthesized
+    ///
+    /// Text arrives as `ResponseChunk::Text` pieces, forwarded as the
+    /// language backend produces them, followed by a single
+    /// `ResponseChunk::Complete` carrying the full response and its metadata.
+    /// Response filters and middleware may rewrite the whole text, so while
+    /// any are registered it is held back and sent as one piece once they
+    /// have run; a client never sees text they would have masked or cut.
+    /// A backend that overruns `stage_timeout` is abandoned for
+    /// `TemplateBackend`; text it already streamed is superseded by the
+    /// `Complete` response, which carries the fallback and its degradation.
+    /// Dropping the receiver before the response is complete abandons the
+    /// interaction with `ProcessingError::Cancelled` and rolls Lia back as
+    /// `process_interaction_with_cancel` does, which costs one clone of Lia
+    /// per call.
+    #[tracing::instrument(skip_all, fields(interaction_id = %input.id, degraded = false))]
+    pub async fn process_interaction_streamed(
+        &mut self,
+        input: &Interaction,
+        chunks: mpsc::Sender<ResponseChunk>,
+    ) -> Result<(), ProcessingError> {
+        // Early stages mutate subsystems as they go, so keep a copy to roll back to
+        let before = self.clone();
+        let logged_through = self.event_log.as_ref().map(EventLog::next_sequence);
+        let result = self.stream_interaction(input, &chunks).await;
+        if matches!(result, Err(ProcessingError::Cancelled)) {
+            self.roll_back(before, logged_through);
+        }
+        result
+    }
+
+    /// `process_interaction_streamed` without the rollback
+    async fn stream_interaction(&mut self, input: &Interaction, chunks: &mpsc::Sender<ResponseChunk>) -> Result<(), ProcessingError> {
+        let input = self.prepare_input(input).await?;
+        let input = &*input;
+        let stages = self.run_stages(input).await?;
+        let mut response = self.response_skeleton(&stages);
+
+        let hold_back = self.response_synthesizer.has_filters() || !self.middleware.is_empty();
+
+        let started = Instant::now();
+        let prompt = stages.prompt(input);
+        let deadline = tokio::time::Instant::now() + self.configuration.stage_timeout;
+        let mut stream = self.response_synthesizer.create_natural_response_stream(prompt.clone());
+        let mut timed_out = false;
+        loop {
+            let piece = match tokio::time::timeout_at(deadline, stream.next()).await {
+                Ok(Some(piece)) => piece,
+                Ok(None) => break,
+                Err(_) => {
+                    timed_out = true;
+                    break;
+                }
+            };
+            response.content.push_str(&piece);
+            let receiving = if hold_back {
+                !chunks.is_closed()
+            } else {
+                chunks.send(ResponseChunk::Text(piece)).await.is_ok()
+            };
+            if !receiving {
+                return Err(ProcessingError::Cancelled);
+            }
+        }
+        // The stream borrows the synthesizer; release it before anything is committed
+        drop(stream);
+        if timed_out {
+            response.content = self.response_synthesizer.create_fallback_response(prompt).await;
+            self.synthesis_timed_out(&mut response);
+        }
+        if response.content.trim().is_empty() {
+            return Err(ProcessingError::SynthesisFailed(SynthesisError::EmptyOutput.to_string()));
+        }
+        StageTimings::record(&mut response.timings, |t| &mut t.synthesis, started);
+        self.response_synthesizer.apply_filters(&mut response);
+        self.run_middleware_after(&mut response).await?;
+        if hold_back && chunks.send(ResponseChunk::Text(response.content.clone())).await.is_err() {
+            return Err(ProcessingError::Cancelled);
+        }
+
+        self.evolve_consciousness(&response).await;
+        self.log_interaction(input, false);
+        self.integrate_interaction(input, &response).await;
+        // Everything is committed by now, so a receiver gone at the last moment changes nothing
+        let _ = chunks.send(ResponseChunk::Complete(Box::new(response))).await;
+        Ok(())
+    }
+
+    /// Process interactions in order, carrying evolution from one to the next
+    pub async fn process_batch(&mut self, inputs: Vec<Interaction>) -> Vec<Result<Response, ProcessingError>> {
+        let started = Instant::now();
+        let mut results = Vec::with_capacity(inputs.len());
+        for input in &inputs {
+            results.push(self.process_interaction(input).await);
+        }
+        self.report_batch("process_batch", started, &results);
+        results
+    }
+
+    /// Replay interactions to update memory, relationships, and metrics without synthesis
+    ///
+    /// Skipping natural-language generation makes this much cheaper than
+    /// `process_batch`; the returned responses carry metadata but empty content.
+    pub async fn process_batch_learn_only(&mut self, inputs: Vec<Interaction>) -> Vec<Result<Response, ProcessingError>> {
+        let started = Instant::now();
+        let mut results = Vec::with_capacity(inputs.len());
+        for input in &inputs {
+            results.push(self.learn_from(input).await);
+        }
+        self.report_batch("process_batch_learn_only", started, &results);
+        results
+    }
+
+    /// Run the full pipeline minus synthesis and apply all of its effects
+    #[tracing::instrument(skip_all, fields(interaction_id = %input.id, degraded = false))]
+    async fn learn_from(&mut self, input: &Interaction) -> Result<Response, ProcessingError> {
+        let input = self.prepare_input(input).await?;
+        let input = &*input;
+        let stages = self.run_stages(input).await?;
+        let response = self.response_skeleton(&stages);
+        self.evolve_consciousness(&response).await;
+        self.log_interaction(input, true);
+        self.integrate_interaction(input, &response).await;
+        Ok(response)
+    }
+
+    /// Log aggregate batch timing when telemetry is enabled
+    fn report_batch(&self, operation: &'static str, started: Instant, results: &[Result<Response, ProcessingError>]) {
+        if !self.configuration.telemetry {
+            return;
+        }
+
+        let elapsed = started.elapsed();
+        let failed = results.iter().filter(|result| result.is_err()).count();
+        let mean = elapsed.checked_div(u32::try_from(results.len()).unwrap_or(u32::MAX)).unwrap_or_default();
+        tracing::info!(
+            operation,
+            total = results.len(),
+            failed,
+            elapsed = ?elapsed,
+            mean_per_interaction = ?mean,
+            "batch complete"
+        );
+    }
+
+    /// Process an interaction, keeping what early stages produced if a later one fails
+    ///
+    /// With `partial_responses` on, a failure after the quantum stage yields
+    /// `ProcessingOutcome::Partial` carrying the coherence, patterns and any
+    /// other stage results that completed, so a caller can still show what
+    /// was sensed. A partial interaction is not learned from. With the option
+    /// off, or when validation, context analysis or the quantum stage
+    /// fails, this behaves like `process_interaction`.
+    #[tracing::instrument(skip_all, fields(interaction_id = %input.id, degraded = false))]
+    pub async fn process_interaction_outcome(&mut self, input: &Interaction) -> Result<ProcessingOutcome, ProcessingError> {
+        if !self.configuration.partial_responses {
+            return self.process_interaction(input).await.map(ProcessingOutcome::Complete);
+        }
+        let input = self.prepare_input(input).await?;
+        let input = &*input;
+        let (context, quantum_state, degradations, timings) = self.run_early_stages(input).await?;
+
+        // Keep what is already known in case a later stage fails
+        let mut sensed = Response::new();
+        sensed.quantum_coherence = quantum_state.coherence;
+        sensed.confidence = Confidence::for_coherence(quantum_state.coherence, self.configuration.min_coherence_for_confident_response);
+        if self.configuration.enabled().pattern_recognition {
+            sensed.detected_patterns = self.pattern_recognition.detect(&context).into_iter().take(MAX_SURFACED_PATTERNS).collect();
+        }
+        sensed.degradations = degradations.entries().to_vec();
+
+        let stages = match self.derive_stages(input, context, quantum_state, degradations, timings).await {
+            Ok(stages) => stages,
+            Err(error) => return Ok(ProcessingOutcome::Partial(PartialResponse { sensed, error })),
+        };
+        self.entangle_thoughts(&stages);
+
+        let response = match self.generate_response(input, &stages).await {
+            Ok(response) => response,
+            Err(error) => {
+                let sensed = self.response_skeleton(&stages);
+                return Ok(ProcessingOutcome::Partial(PartialResponse { sensed, error }));
+            }
+        };
         self.evolve_consciousness(&response).await;
+        self.log_interaction(input, false);
+        self.integrate_interaction(input, &response).await;
+        Ok(ProcessingOutcome::Complete(response))
+    }
+
+    /// Process an interaction, logging any failure and falling back to an empty response
+    pub async fn process_interaction_lossy(&mut self, input: &Interaction) -> Response {
+        match self.process_interaction(input).await {
+            Ok(response) => response,
+            Err(err) => {
+                tracing::warn!(error = %err, "interaction processing failed; returning fallback response");
+                let mut response = Response::new();
+                response.degradations.push(Degradation {
+                    subsystem: "pipeline".to_string(),
+                    reason: format!("fell back to an empty response: {}", err),
+                });
+                response
+            }
+        }
+    }
+
+    /// Build the structured context every later stage works from
+    #[tracing::instrument(skip_all, fields(interaction_id = %input.id))]
+    async fn analyze_context(&self, input: &Interaction) -> Result<Context, ProcessingError> {
+        if input.content.trim().is_empty() {
+            return Err(ProcessingError::ContextAnalysisFailed("interaction has no content".to_string()));
+        }
+
+        let topics: Vec<Topic> = self
+            .interaction_processor
+            .concept_counts(&input.content)
+            .into_iter()
+            .map(|(concept, mentions)| Topic {
+                known: self.semantic_memory.contains(&concept),
+                concept,
+                mentions,
+            })
+            .collect();
+        let novelty = if topics.is_empty() {
+            0.0
+        } else {
+            topics.iter().filter(|topic| !topic.known).count() as f64 / topics.len() as f64
+        };
+        let mut referenced_people: Vec<PersonId> = input
+            .context
+            .mentioned_people
+            .iter()
+            .copied()
+            .filter(|person| Some(*person) != input.speaker)
+            .collect();
+        referenced_people.sort();
+        referenced_people.dedup();
+
+        Ok(Context {
+            interaction_id: input.id,
+            content: input.content.clone(),
+            speaker: input.speaker,
+            depth: input.context.depth_level,
+            sentiment: estimate_sentiment(&input.content),
+            caller_emotion: input.context.emotional_state.clone(),
+            topics,
+            referenced_people,
+            novelty,
+            conversation_summary: self.conversation.summary().to_string(),
+            idle_gap: self.time_since_last_interaction(),
+            group_tone: input
+                .speaker
+                .filter(|_| self.configuration.emotional_contagion > 0.0)
+                .and_then(|speaker| self.relationship_manager.group_tone(speaker, self.clock.now())),
+        })
+    }
+
+    /// Analyze `input` the way processing would, without running any later stage
+    ///
+    /// Nothing is mutated, so this is safe for routing and analytics before
+    /// deciding whether to process the interaction at all.
+    pub async fn analyze_context_public(&self, input: &Interaction) -> Result<Context, ProcessingError> {
+        let input = self.prepare_input(input).await?;
+        let input = &*input;
+        input.validate_within(self.configuration.max_interaction_bytes)?;
+        self.analyze_context(input).await
+    }
+
+    /// Run every processing stage that precedes response synthesis
+    ///
+    /// Each stage gets `stage_timeout` to finish; a stage that overruns is
+    /// replaced by its neutral default so the interaction still completes.
+    /// Malformed input is rejected before any stage runs.
+    async fn run_stages(&mut self, input: &Interaction) -> Result<ProcessedStages, ProcessingError> {
+        let (context, quantum_state, degradations, timings) = self.run_early_stages(input).await?;
         
-        response
+        // Remaining stages only read state
+        let stages = self.derive_stages(input, context, quantum_state, degradations, timings).await?;
+        self.entangle_thoughts(&stages);
+        Ok(stages)
     }
 
-    /// Generate integrated response using all processing systems
-    async fn generate_response(
+    /// Validate the input, then run context analysis and the quantum stage, the only ones that mutate Lia
+    async fn run_early_stages(
+        &mut self,
+        input: &Interaction,
+    ) -> Result<(Context, QuantumState, DegradationLog, Option<StageTimings>), ProcessingError> {
+        self.state_changed();
+        input.validate_within(self.configuration.max_interaction_bytes)?;
+        let budget = self.configuration.stage_timeout;
+        let enabled = self.configuration.enabled();
+        let mut timings = self.configuration.telemetry.then(StageTimings::default);
+
+        // Generate deep context analysis
+        let mut degradations = DegradationLog::default();
+        let started = Instant::now();
+        let outcome = within_budget("context_analysis", budget, self.analyze_context(input)).await;
+        let context = match outcome {
+            Some(result) => result?,
+            None => self.stage_timed_out("context_analysis", &mut degradations),
+        };
+        StageTimings::record(&mut timings, |t| &mut t.context_analysis, started);
+
+        // Let coherence relax toward baseline for however long we sat idle
+        self.decohere_idle(self.clock.now());
+        
+        // Quantum processing
+        let started = Instant::now();
+        let quantum_state = if enabled.quantum_core {
+            let outcome = within_budget("quantum_core", budget, self.quantum_core.process(&context)).await;
+            self.settle_stage("quantum_core", outcome, &mut degradations)?
+        } else {
+            QuantumState::default()
+        };
+        StageTimings::record(&mut timings, |t| &mut t.quantum_core, started);
+        
+        Ok((context, quantum_state, degradations, timings))
+    }
+
+    /// Entangle the new thoughts with earlier ones that share their concepts
+    fn entangle_thoughts(&mut self, stages: &ProcessedStages) {
+        if self.configuration.enabled().quantum_thought_processor {
+            self.quantum_thought_processor.entangle(&stages.thought_patterns);
+        }
+    }
+
+    /// Run the stages that follow quantum measurement, none of which mutate Lia
+    async fn derive_stages(
         &self,
         input: &Interaction,
-        context: &Context,
-        quantum_state: &QuantumState,
-        neural_response: &NeuralResponse,
-        thought_patterns: &[ThoughtPattern],
-        consciousness_response: &ConsciousnessResponse,
-        emotional_response: &EmotionalResponse,
-    ) -> Response {
-        let mut response = Response::new();
+        context: Context,
+        quantum_state: QuantumState,
+        mut degradations: DegradationLog,
+        mut timings: Option<StageTimings>,
+    ) -> Result<ProcessedStages, ProcessingError> {
+        let budget = self.configuration.stage_timeout;
+        let enabled = self.configuration.enabled();
+        let now = self.clock.now();
         
-        // Integrate quantum coherence
-        response.quantum_coherence = quantum_state.coherence;
+        // Decide how much effort this interaction deserves
+        let attention = self.attention_allocator.allocate(&context);
         
-        // Add neural patterns
-        response.neural_patterns = neural_response.patterns.clone();
+        // Pick how the response should be pitched
+        let mode = self.mode_router.route(&context);
         
-        // Add consciousness insights
-        response.consciousness_level = consciousness_response.awareness_level;
+        // Notice recurring patterns in what was said
+        let detected_patterns = if enabled.pattern_recognition && attention.pattern_recognition != Effort::Skip {
+            self.pattern_recognition.detect(&context)
+        } else {
+            Vec::new()
+        };
         
-        // Add emotional understanding
-        response.emotional_layer = emotional_response.clone();
+        // Neural processing
+        let started = Instant::now();
+        let neural_response = if enabled.neural_matrix {
+            let outcome = within_budget(
+                "neural_matrix",
+                budget,
+                self.neural_matrix.process_with_quantum_state(&quantum_state, &context),
+            ).await;
+            self.settle_stage("neural_matrix", outcome, &mut degradations)?
+        } else {
+            NeuralResponse::default()
+        };
+        StageTimings::record(&mut timings, |t| &mut t.neural_matrix, started);
         
-        // Generate natural language response
-        response.content = self.response_synthesizer.create_natural_response(
-            input,
+        // Generate quantum thought patterns
+        let started = Instant::now();
+        let ThoughtGeneration { thoughts: thought_patterns, branches_considered: thought_branches } =
+            if enabled.quantum_thought_processor {
+                let outcome = within_budget(
+                    "quantum_thought_processor",
+                    budget,
+                    self.quantum_thought_processor
+                        .generate_thoughts(&neural_response, &quantum_state, attention.thought_generation, |concept| {
+                            self.emotional_resonance.emotional_weight(concept)
+                        }),
+                ).await;
+                self.settle_stage("quantum_thought_processor", outcome, &mut degradations)?
+            } else {
+                ThoughtGeneration::default()
+            };
+        StageTimings::record(&mut timings, |t| &mut t.quantum_thought_processor, started);
+        
+        // Process through consciousness field
+        let started = Instant::now();
+        let outcome = within_budget(
+            "consciousness_field",
+            budget,
+            self.consciousness_field.process_experience(&context, &thought_patterns),
+        ).await;
+        let consciousness_response = self.settle_stage("consciousness_field", outcome, &mut degradations)?;
+        StageTimings::record(&mut timings, |t| &mut t.consciousness_field, started);
+        
+        // Emotional processing, coloured by the relationship with the speaker
+        let relationship = input
+            .speaker
+            .map(|person| self.relationship_manager.relationship_or_new(person, input.timestamp));
+        let started = Instant::now();
+        let emotional_response = if enabled.emotional_resonance {
+            let outcome = within_budget(
+                "emotional_resonance",
+                budget,
+                self.emotional_resonance
+                    .process_emotion(&context, &consciousness_response, relationship.as_ref(), &mode, now),
+            ).await;
+            self.settle_stage("emotional_resonance", outcome, &mut degradations)?
+        } else {
+            EmotionalResponse::default()
+        };
+        StageTimings::record(&mut timings, |t| &mut t.emotional_resonance, started);
+        
+        // Judge how far the response can be trusted from how coherent the measurement was
+        let confidence = Confidence::for_coherence(quantum_state.coherence, self.configuration.min_coherence_for_confident_response);
+        
+        Ok(ProcessedStages {
             context,
+            attention,
+            detected_patterns,
+            confidence,
             quantum_state,
             neural_response,
             thought_patterns,
+            thought_branches,
             consciousness_response,
             emotional_response,
-        );
-        
+            mode,
+            degradations,
+            timings,
+        })
+    }
+
+    /// Apply idle decoherence since the previous interaction and mark `now` as the latest one
+    fn decohere_idle(&mut self, now: DateTime<Utc>) {
+        if let Some(elapsed) = self.last_interaction_at.and_then(|last| (now - last).to_std().ok()) {
+            if self.configuration.enabled().quantum_core {
+                self.quantum_core.decay(elapsed);
+            }
+        }
+        self.last_interaction_at = Some(now);
+    }
+
+    /// Turn a budgeted stage outcome into its value, a tagged error, or a timeout fallback
+    fn settle_stage<T: Default, E: std::fmt::Display>(
+        &self,
+        stage: &'static str,
+        outcome: Option<Result<T, E>>,
+        degradations: &mut DegradationLog,
+    ) -> Result<T, ProcessingError> {
+        match outcome {
+            Some(result) => result.map_err(|err| ProcessingError::stage(stage, err)),
+            None => Ok(self.stage_timed_out(stage, degradations)),
+        }
+    }
+
+    /// Record on `response` that the language backend overran its budget and the template answered instead
+    fn synthesis_timed_out(&self, response: &mut Response) {
+        tracing::Span::current().record("degraded", true);
+        tracing::warn!(budget = ?self.configuration.stage_timeout, "language backend timed out; using the template backend");
+        self.emit(ConsciousnessEvent::StageTimedOut { stage: "synthesis".to_string() });
+        response.degradations.push(Degradation {
+            subsystem: "synthesis".to_string(),
+            reason: "timed out; fell back to the template backend".to_string(),
+        });
+    }
+
+    /// Record that `stage` overran its budget and hand back its neutral default
+    fn stage_timed_out<T: Default>(&self, stage: &'static str, degradations: &mut DegradationLog) -> T {
+        tracing::Span::current().record("degraded", true);
+        tracing::warn!(stage, budget = ?self.configuration.stage_timeout, "stage timed out; using degraded default");
+        self.emit(ConsciousnessEvent::StageTimedOut { stage: stage.to_string() });
+        degradations.record(stage, "timed out; used neutral default");
+        T::default()
+    }
+
+    /// Time allowed per stage and in total for one interaction's timed stages
+    pub fn stage_budget(&self) -> StageBudget {
+        StageBudget {
+            per_stage: self.configuration.stage_timeout,
+            total: self.configuration.total_stage_budget(),
+        }
+    }
+
+    /// Response metadata for `stages`, with a trace attached when `trace_responses` is on
+    fn response_skeleton(&self, stages: &ProcessedStages) -> Response {
+        let mut response = stages.response_skeleton();
+        if self.configuration.trace_responses {
+            response.trace = Some(ResponseTrace {
+                thought_patterns: stages.thought_patterns.iter().take(TRACED_THOUGHTS).cloned().collect(),
+                thought_branches: stages.thought_branches,
+                caller_emotion: stages.context.caller_emotion().clone(),
+                sentiment: stages.context.sentiment(),
+                emotional_appraisal: stages.emotional_response.state.clone(),
+                dimensional_impacts: self.dimensional_processor.calculate_impacts(&response),
+                topics: stages.context.topics().to_vec(),
+            });
+        }
         response
     }
 
+    /// Generate integrated response using all processing systems
+    #[tracing::instrument(skip_all, fields(interaction_id = %input.id))]
+    async fn generate_response(
+        &self,
+        input: &Interaction,
+        stages: &ProcessedStages,
+    ) -> Result<Response, ProcessingError> {
+        let mut response = self.response_skeleton(stages);
+        
+        // Generate natural language response
+        let started = Instant::now();
+        let synthesized = self.response_synthesizer
+            .create_natural_response_within(stages.prompt(input), self.configuration.stage_timeout)
+            .await
+            .map_err(|err| ProcessingError::SynthesisFailed(err.to_string()))?;
+        response.content = match synthesized {
+            Synthesized::Rendered(text) => text,
+            Synthesized::FellBack(text) => {
+                self.synthesis_timed_out(&mut response);
+                text
+            }
+        };
+        StageTimings::record(&mut response.timings, |t| &mut t.synthesis, started);
+        
+        // Apply content policies and formatting
+        self.response_synthesizer.apply_filters(&mut response);
+        self.run_middleware_after(&mut response).await?;
+        
+        Ok(response)
+    }
+
     /// Evolve consciousness based on interaction experience
+    ///
+    /// Growth, learning and metrics take in every response immediately. The
+    /// rest is applied per `evolution_batch_size`: a batch of one evolves
+    /// straight away, while a larger batch waits until it fills or has
+    /// waited `evolution_batch_interval`, then evolves once for the burst.
+    #[tracing::instrument(skip_all)]
     async fn evolve_consciousness(&mut self, response: &Response) {
-        // Track growth
+        let now = self.clock.now();
+
+        // Track growth, learning and metrics per interaction
         self.growth_tracker.record_growth(response);
+        self.learning_engine.integrate_experience(response).await;
+        self.evolution_metrics.record_evolution(response, now);
+
+        if self.configuration.evolution_batch_size <= 1 && self.pending_evolution.is_empty() {
+            self.apply_evolution(response).await;
+            return;
+        }
+        self.pending_evolution.push(response.clone(), now);
+        if self.pending_evolution.is_due(&self.configuration, now) {
+            self.apply_pending_evolution().await;
+        }
+    }
+
+    /// Evolve once for everything waiting in the batch
+    async fn apply_pending_evolution(&mut self) {
+        if let Some(combined) = self.pending_evolution.take_combined() {
+            self.apply_evolution(&combined).await;
+        }
+    }
+
+    /// Adjust every subsystem toward `response` and advance a stage if growth allows
+    async fn apply_evolution(&mut self, response: &Response) {
+        let enabled = self.configuration.enabled();
         
         // Update quantum state
-        self.quantum_core.evolve(response).await;
+        if enabled.quantum_core {
+            self.quantum_core.evolve(response).await;
+        }
         
-        // Evolve neural patterns
-        self.neural_matrix.evolve_patterns(response).await;
+        // Evolve neural patterns, reporting any that were pruned to stay bounded
+        if enabled.neural_matrix {
+            let pruned = self.neural_matrix.evolve_patterns(response).await;
+            if !pruned.is_empty() {
+                self.emit(ConsciousnessEvent::PatternsPruned { patterns: pruned });
+            }
+        }
         
         // Update consciousness field
         self.consciousness_field.evolve(response).await;
         
         // Evolve emotional processing
-        self.emotional_resonance.evolve(response).await;
-        
-        // Update learning models
-        self.learning_engine.integrate_experience(response).await;
+        if enabled.emotional_resonance {
+            let previous_emotion = self.emotional_resonance.current_state();
+            self.emotional_resonance.evolve(response).await;
+            let emotion = self.emotional_resonance.current_state();
+            if emotion != previous_emotion {
+                self.emit(ConsciousnessEvent::EmotionChanged { state: emotion });
+            }
+        }
         
         // Update dimensional state
-        self.update_dimensional_state(response);
-        
-        // Track evolution metrics
-        self.evolution_metrics.record_evolution(response);
+        if enabled.dimensional_processor {
+            self.update_dimensional_state(response);
+        }
         
         // Update system state
         self.state_manager.update_state(self.current_state());
+        self.publish_state();
+        
+        // Advance a stage once accumulated growth crosses the threshold
+        self.evolve().await;
+    }
+
+    /// Record an interaction in memory and in the speaker's relationship
+    async fn integrate_interaction(&mut self, input: &Interaction, response: &Response) {
+        if self.configuration.enabled().pattern_recognition {
+            self.pattern_recognition.observe(&input.content);
+        }
+        if let Some(person) = input.speaker {
+            self.relationship_manager.record_interaction(person, input.timestamp);
+            self.relationship_manager.record_tone(person, &response.emotional_layer.appraisal);
+        }
+        self.process_memory(&Experience::from(input)).await;
+        
+        // Keep the conversation window bounded, folding older turns into the summary
+        let overflow = self.conversation.push(input.content.clone());
+        if !overflow.is_empty() {
+            let summary = self.response_synthesizer.summarize(self.conversation.summary(), &overflow).await;
+            self.conversation.set_summary(summary);
+        }
+
+        // Hand the oldest interaction to subscribers once history is full
+        let exchange = Exchange {
+            interaction: input.clone(),
+            response: response.clone(),
+            responded_at: self.clock.now(),
+        };
+        if let Some(evicted) = self.history.push(exchange) {
+            self.emit(ConsciousnessEvent::InteractionEvicted {
+                interaction: Box::new(evicted.interaction),
+                response: Box::new(evicted.response),
+            });
+        }
+
+        #[cfg(feature = "otel")]
+        otel::export_interaction(input.id, response);
     }
 
     /// Process and integrate memory
     async fn process_memory(&mut self, experience: &Experience) {
-        // Process episodic memory
-        self.episodic_memory.integrate_experience(experience).await;
+        // Process episodic memory, fingerprinted with how Lia felt in the moment
+        let emotion = self.emotional_resonance.current_state();
+        let now = self.clock.now();
+        let memory_id = self.episodic_memory.integrate_experience(experience, &emotion, now).await;
+        self.evolution_metrics.record_memory_counts(self.episodic_memory.len());
+        self.emit(ConsciousnessEvent::MemoryStored { id: memory_id });
         
-        // Update semantic knowledge
-        self.semantic_memory.integrate_knowledge(experience).await;
+        // Update semantic knowledge, surfacing anything it contradicts
+        for contradiction in self.semantic_memory.integrate_knowledge(experience, now).await {
+            self.emit(ConsciousnessEvent::ContradictionDetected { contradiction });
+        }
         
         // Update procedural memory
         self.procedural_memory.integrate_learning(experience).await;
@@ -223,38 +988,9708 @@ impl Lia {
         // Calculate dimensional impacts
         let impacts = self.dimensional_processor.calculate_impacts(response);
         
-        // Update dimensional values
-        self.dimensional_state.update(impacts);
+        // Update dimensional values, discarding impacts that would corrupt the state
+        let previous = self.dimensional_state.clone();
+        if let Err(err) = self.dimensional_state.update(impacts, &self.configuration.dimension_bounds) {
+            tracing::warn!(error = %err, "dimensional impacts rejected; state left unchanged");
+            return;
+        }
+        self.emit(ConsciousnessEvent::DimensionalShift {
+            delta: self.dimensional_state.delta_from(&previous),
+        });
         
         // Process through consciousness field
         self.consciousness_field.process_dimensional_change(&self.dimensional_state);
         
         // Update evolution metrics
-        self.evolution_metrics.record_dimensional_change(&self.dimensional_state);
+        self.evolution_metrics.record_dimensional_change(&self.dimensional_state, self.clock.now());
     }
 }
 
-/// Implementation of core consciousness capabilities
-#[async_trait]
-impl ConsciousnessCapable for Lia {
-    async fn process_experience(&mut self, experience: Experience) -> Response {
-        let context = self.analyze_context(&experience).await;
-        self.process_interaction(&experience.into()).await
+/// Event publishing
+impl Lia {
+    /// Subscribe to live consciousness events
+    ///
+    /// Receivers that fall more than the channel capacity behind observe
+    /// `RecvError::Lagged` and resume from the oldest retained event.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ConsciousnessEvent> {
+        self.events.subscribe()
     }
 
-    async fn evolve(&mut self) {
-        self.evolution_stage += 1;
-        self.state_manager.record_evolution(self.evolution_stage);
+    /// Publish an event, and log it when logging; having no subscribers is not an error
+    fn emit(&self, event: ConsciousnessEvent) {
+        if let Some(log) = &self.event_log {
+            log.append(self.clock.now(), LogEntry::Event(event.clone()));
+        }
+        let _ = self.events.send(event);
     }
 
-    fn current_state(&self) -> ConsciousnessState {
-        ConsciousnessState {
-            id: self.id,
-            evolution_stage: self.evolution_stage,
-            dimensional_state: self.dimensional_state.clone(),
-            quantum_coherence: self.quantum_core.coherence(),
-            consciousness_level: self.consciousness_field.awareness_level(),
-            emotional_state: self.emotional_resonance.current_state(),
+    /// Emit `PlateauReached` the first time the plateau is reached
+    fn announce_plateau(&mut self) {
+        if std::mem::replace(&mut self.plateau_announced, true) {
+            return;
+        }
+        self.emit(ConsciousnessEvent::PlateauReached { stage: self.evolution_stage });
+    }
+
+    /// Make the latest state visible to every `LiaHandle`
+    fn publish_state(&self) {
+        self.state_tx.send_replace(self.current_state());
+    }
+
+    /// Cheap, cloneable read-side handle that can be moved into spawned tasks
+    pub fn handle(&self) -> LiaHandle {
+        LiaHandle {
+            state: self.state_tx.subscribe(),
+            events: self.events.clone(),
+        }
+    }
+}
+
+/// Memory maintenance
+impl Lia {
+    /// Promote concepts that recur across recent experiences into semantic memory
+    ///
+    /// Only experiences stored since the previous consolidation are scanned,
+    /// so running this again without new experiences changes nothing.
+    pub fn consolidate(&mut self) -> ConsolidationReport {
+        self.log(LogEntry::Consolidated);
+        self.state_changed();
+        let recent: Vec<&EpisodicEntry> = self.episodic_memory.entries_after(self.consolidated_through).collect();
+        let Some(latest) = recent.iter().map(|entry| entry.recorded_at).max() else {
+            return ConsolidationReport::default();
+        };
+
+        let experiences: Vec<&Experience> = recent.iter().map(|entry| &entry.experience).collect();
+        let recurring = self.pattern_recognition.recurring_concepts(&experiences);
+
+        let mut report = ConsolidationReport {
+            experiences_scanned: experiences.len(),
+            ..ConsolidationReport::default()
+        };
+        let now = self.clock.now();
+        for (concept, _support) in recurring {
+            if self.semantic_memory.reinforce_concept(concept, now) {
+                report.concepts_created = report.concepts_created.saturating_add(1);
+            } else {
+                report.concepts_reinforced = report.concepts_reinforced.saturating_add(1);
+            }
+        }
+
+        self.consolidated_through = Some(latest);
+        report
+    }
+}
+
+/// Memory queries
+impl Lia {
+    /// Search episodic, semantic and procedural memory together, best match first
+    ///
+    /// Every system scores hits in `[0, 1]`, so they are ranked on one scale;
+    /// equal scores keep episodes first, newest first, then concepts and
+    /// then skills in label order.
+    pub fn query_memory(&self, query: MemoryQuery) -> MemoryResults {
+        // Only the best `limit` episodes can make the cut, so clone no more than those
+        let mut episodes = self.episodic_memory.search(&query, self.clock.now());
+        episodes.sort_by(|(_, a), (_, b)| b.total_cmp(a));
+        episodes.truncate(query.limit);
+        let episodic = episodes.into_iter().map(|(entry, score)| MemoryHit::Episodic {
+            experience: entry.experience.clone(),
+            recorded_at: entry.recorded_at,
+            score,
+        });
+        let semantic = self
+            .semantic_memory
+            .search(&query)
+            .into_iter()
+            .map(|(concept, score)| MemoryHit::Semantic { concept, score });
+        let procedural = self
+            .procedural_memory
+            .search(&query)
+            .into_iter()
+            .map(|(skill, score)| MemoryHit::Procedural { skill: skill.clone(), score });
+
+        let mut hits: Vec<MemoryHit> = episodic.chain(semantic).chain(procedural).collect();
+        hits.sort_by(|a, b| b.score().total_cmp(&a.score()));
+        hits.truncate(query.limit);
+        MemoryResults { hits }
+    }
+}
+
+/// Experiences replayed by each `dream`
+const DREAM_SAMPLE_SIZE: usize = 16;
+
+/// Replay passes made over the sampled experiences
+const DREAM_EPOCHS: usize = 3;
+
+/// Offline learning
+impl Lia {
+    /// Replay the most salient episodic memories to stabilize learning
+    ///
+    /// Useful after a burst of novel interactions. Returns how many
+    /// experiences were replayed.
+    pub fn dream(&mut self) -> usize {
+        self.log(LogEntry::Dreamed);
+        self.state_changed();
+        let experiences: Vec<Experience> = self
+            .episodic_memory
+            .recall_most_salient(DREAM_SAMPLE_SIZE, self.clock.now())
+            .into_iter()
+            .cloned()
+            .collect();
+        self.learning_engine.replay(&experiences, DREAM_EPOCHS);
+        experiences.len()
+    }
+}
+
+/// Feedback
+impl Lia {
+    /// Reinforce or discourage the patterns that produced a recent response
+    ///
+    /// Both learned affinities and neural pattern weights move, so later
+    /// interactions touching the same concepts activate them more or less
+    /// strongly. See `LearningEngine::apply_feedback` for which responses
+    /// can still be rated.
+    pub fn apply_feedback(&mut self, response_id: Uuid, feedback: Feedback) -> Result<(), FeedbackError> {
+        let adjustments = self.learning_engine.apply_feedback(response_id, &feedback)?;
+        let pruned = self.neural_matrix.adjust_weights(&adjustments);
+        self.log(LogEntry::Feedback { response_id, feedback });
+        self.state_changed();
+        if !pruned.is_empty() {
+            self.emit(ConsciousnessEvent::PatternsPruned { patterns: pruned });
+        }
+        self.publish_state();
+        Ok(())
+    }
+}
+
+/// Debounced evolution
+impl Lia {
+    /// Apply evolution for any batched interactions now, without waiting for the batch to fill
+    ///
+    /// Until this or the batch fills, `current_state()` shows only the
+    /// evolution already applied.
+    pub async fn flush_evolution(&mut self) {
+        if self.pending_evolution.is_empty() {
+            return;
+        }
+        self.log(LogEntry::EvolutionFlushed);
+        self.state_changed();
+        self.apply_pending_evolution().await;
+    }
+
+    /// Interactions whose evolution is waiting in the batch
+    pub fn pending_evolution(&self) -> usize {
+        self.pending_evolution.len()
+    }
+}
+
+/// Cancellation
+impl Lia {
+    /// Process an interaction unless `token` is cancelled first
+    ///
+    /// Cancellation takes effect at the next await point in any stage. A
+    /// cancelled interaction is rolled back completely, so `current_state()`
+    /// and every subsystem are exactly as they were before the call; only
+    /// events already delivered to subscribers cannot be recalled. Holding
+    /// the rollback copy costs one clone of Lia per call.
+    pub async fn process_interaction_with_cancel(
+        &mut self,
+        input: &Interaction,
+        token: &CancellationToken,
+    ) -> Result<Response, ProcessingError> {
+        if token.is_cancelled() {
+            return Err(ProcessingError::Cancelled);
+        }
+
+        // Stages mutate subsystems as they go, so keep a copy to roll back to
+        let before = self.clone();
+        let logged_through = self.event_log.as_ref().map(EventLog::next_sequence);
+        let outcome = tokio::select! {
+            biased;
+            _ = token.cancelled() => None,
+            result = self.process_interaction(input) => Some(result),
+        };
+
+        match outcome {
+            Some(result) => result,
+            None => {
+                self.roll_back(before, logged_through);
+                Err(ProcessingError::Cancelled)
+            }
         }
     }
+
+    /// Return to `before`, dropping anything logged from `logged_through` on
+    fn roll_back(&mut self, before: Lia, logged_through: Option<u64>) {
+        let mut processor = std::mem::take(&mut self.interaction_processor);
+        processor.return_to(&before.interaction_processor);
+        *self = before;
+        self.interaction_processor = processor;
+        if let (Some(log), Some(sequence)) = (&self.event_log, logged_through) {
+            log.rewind(sequence);
+        }
+        self.publish_state();
+    }
+}
+
+/// Health checks
+impl Lia {
+    /// Check every subsystem's invariants without mutating anything
+    pub fn health(&self) -> HealthReport {
+        let subsystems = vec![
+            self.quantum_core.health(),
+            self.neural_matrix.health(),
+            self.emotional_resonance.health(),
+            self.dimensional_state.health(&self.configuration.dimension_bounds),
+            self.episodic_memory.health(),
+        ];
+        let status = subsystems
+            .iter()
+            .map(|subsystem| subsystem.status)
+            .max()
+            .unwrap_or(HealthStatus::Healthy);
+
+        HealthReport {
+            status,
+            subsystems,
+            memory_episodic_count: self.episodic_memory.len(),
+            last_interaction_at: self.last_interaction_at,
+        }
+    }
+}
+
+/// Dry runs
+impl Lia {
+    /// Compute the response `input` would receive without changing any state
+    ///
+    /// Runs the full pipeline but skips idle decoherence, quantum phase
+    /// advance, thought entanglement, evolution and memory, so
+    /// `current_state()` is identical before and after. Useful for ranking
+    /// candidate inputs.
+    ///
+    /// With `rng_seed` set and a non-zero `response_cache_capacity`, the
+    /// response is cached by state generation and input, so repeating a
+    /// preview in an unchanged state skips the pipeline; see
+    /// `InteractionProcessor`.
+    #[tracing::instrument(skip_all, fields(interaction_id = %input.id, degraded = false))]
+    pub async fn preview_interaction(&self, input: &Interaction) -> Result<Response, ProcessingError> {
+        let cache_key = self.response_cache_key(input, true);
+        if let Some(response) = self.cached_response(cache_key, input) {
+            return Ok(response);
+        }
+
+        let response = self.preview_uncached(input).await?;
+        if let Some(key) = cache_key {
+            self.interaction_processor
+                .cache_response(key, response.clone(), self.configuration.response_cache_capacity);
+        }
+        Ok(response)
+    }
+
+    async fn preview_uncached(&self, input: &Interaction) -> Result<Response, ProcessingError> {
+        let input = self.prepare_input(input).await?;
+        let input = &*input;
+        input.validate_within(self.configuration.max_interaction_bytes)?;
+        let budget = self.configuration.stage_timeout;
+        let mut timings = self.configuration.telemetry.then(StageTimings::default);
+
+        let mut degradations = DegradationLog::default();
+        let started = Instant::now();
+        let outcome = within_budget("context_analysis", budget, self.analyze_context(input)).await;
+        let context = match outcome {
+            Some(result) => result?,
+            None => self.stage_timed_out("context_analysis", &mut degradations),
+        };
+        StageTimings::record(&mut timings, |t| &mut t.context_analysis, started);
+        let started = Instant::now();
+        let quantum_state = if self.configuration.enabled().quantum_core {
+            self.quantum_core
+                .observe()
+                .map_err(|err| ProcessingError::stage("quantum_core", err))?
+        } else {
+            QuantumState::default()
+        };
+        StageTimings::record(&mut timings, |t| &mut t.quantum_core, started);
+
+        let stages = self.derive_stages(input, context, quantum_state, degradations, timings).await?;
+        self.generate_response(input, &stages).await
+    }
+
+    /// Where `input` is cached for the current state, or `None` when caching can't be trusted
+    ///
+    /// Without a seed, identical state and input can still produce different
+    /// responses, so caching is off regardless of capacity. Previews skip
+    /// some effects, so they are cached apart from processed responses.
+    /// Idle decoherence, the context's idle gap and relationship fading all
+    /// depend on how long ago the state was last touched, so the clock's
+    /// current time is part of the key; under a clock that keeps moving
+    /// nothing is reused.
+    fn response_cache_key(&self, input: &Interaction, previewed: bool) -> Option<ResponseKey> {
+        if self.configuration.response_cache_capacity == 0 || self.configuration.rng_seed.is_none() {
+            return None;
+        }
+        // Ids and timestamps differ between otherwise identical inputs
+        let now = self.clock.now();
+        let input =
+            serde_json::to_vec(&(previewed, now, &input.content, &input.context, input.speaker, input.style, &input.modality)).ok()?;
+        Some(self.interaction_processor.key(fnv1a(&input)))
+    }
+
+    /// Response cached under `key`, re-identified as the answer to `input`
+    fn cached_response(&self, key: Option<ResponseKey>, input: &Interaction) -> Option<Response> {
+        let mut response = self.interaction_processor.cached_response(key?)?;
+        response.id = Uuid::new_v5(&input.id, b"response");
+        Some(response)
+    }
+
+    /// Move to a fresh generation after anything that changes learned state
+    fn state_changed(&mut self) {
+        self.interaction_processor.advance_generation();
+    }
+
+    /// Stop reusing responses after a backend, filter, mode, middleware or extractor is registered
+    fn wiring_changed(&mut self) {
+        self.interaction_processor.advance_wiring();
+    }
+
+    /// How often previews and processed interactions were answered from the response cache
+    pub fn response_cache_stats(&self) -> ResponseCacheStats {
+        self.interaction_processor.response_cache_stats()
+    }
+}
+
+/// Federated learning
+impl Lia {
+    /// Combine what `other` learned into this instance
+    ///
+    /// Semantic memory, neural pattern weights and learned affinities are
+    /// merged per `strategy`. Identity, memories of lived experiences,
+    /// relationships and configuration stay with `self`. Keys are visited in
+    /// order, so merging the same two instances always gives the same result.
+    pub fn merge_learnings(&mut self, other: &Lia, strategy: MergeStrategy) {
+        self.state_changed();
+        self.semantic_memory.merge_from(&other.semantic_memory, strategy);
+        self.learning_engine.merge_from(&other.learning_engine, strategy);
+        let pruned = self.neural_matrix.merge_from(&other.neural_matrix, strategy);
+        if !pruned.is_empty() {
+            self.emit(ConsciousnessEvent::PatternsPruned { patterns: pruned });
+        }
+    }
+}
+
+/// Relationship migration
+impl Lia {
+    /// Load relationships exported from another instance; see `RelationshipManager::import`
+    pub fn import_relationships(&mut self, data: RelationshipExport, merge: bool) {
+        self.relationship_manager.import(data, merge);
+        self.state_changed();
+    }
+}
+
+/// Social graph
+impl Lia {
+    /// Record that `a` and `b` know each other, so with `emotional_contagion` on each one's tone colours the other's
+    pub fn link_people(&mut self, a: PersonId, b: PersonId) {
+        self.relationship_manager.link(a, b);
+        self.state_changed();
+    }
+
+    pub fn unlink_people(&mut self, a: PersonId, b: PersonId) {
+        self.relationship_manager.unlink(a, b);
+        self.state_changed();
+    }
+}
+
+/// Targeted resets
+impl Lia {
+    /// Restore the emotional engine to a neutral mood with no trajectory
+    pub fn reset_emotions(&mut self) {
+        self.emotional_resonance = EmotionalResonanceEngine::new(&self.configuration);
+        self.subsystem_reset("emotional_resonance");
+    }
+
+    /// Forget every episode; semantic and procedural memory are kept
+    pub fn reset_episodic_memory(&mut self) {
+        self.episodic_memory = EpisodicMemorySystem::new(&self.configuration);
+        self.subsystem_reset("episodic_memory");
+    }
+
+    /// Restore the quantum core to its configured initial coherence
+    pub fn reset_quantum_core(&mut self) {
+        self.quantum_core = QuantumCore::new(&self.configuration);
+        self.subsystem_reset("quantum_core");
+    }
+
+    fn subsystem_reset(&mut self, name: &str) {
+        self.state_changed();
+        self.emit(ConsciousnessEvent::SubsystemReset { name: name.to_string() });
+        self.publish_state();
+    }
+}
+
+/// Checkpoints and rollback
+impl Lia {
+    /// Capture every subsystem so it can later be restored with `restore_checkpoint`
+    ///
+    /// Only the most recent `max_checkpoints` are retained.
+    pub fn checkpoint(&mut self) -> CheckpointId {
+        // Detach the state manager so the capture does not nest earlier checkpoints
+        let manager = std::mem::replace(&mut self.state_manager, StateManager::new(&self.configuration));
+        let captured = Arc::new(self.clone());
+        self.state_manager = manager;
+        let id = self.state_manager.store_checkpoint(captured);
+        self.log(LogEntry::Checkpointed { id });
+        id
+    }
+
+    /// Revert all learned state to a retained checkpoint
+    ///
+    /// The configuration, checkpoint history and event subscribers are
+    /// kept, as is everything registered at runtime: the language backend,
+    /// filters, middleware, modality extractors, modes, clock and event
+    /// log. Both caches stay warm, and responses cached in the checkpointed
+    /// state can be reused again. The restore is logged so
+    /// `rebuild_from_log` can replay it.
+    pub fn restore_checkpoint(&mut self, id: CheckpointId) -> Result<(), StateError> {
+        let captured = self.state_manager.checkpoint(id)?;
+        let mut learned = (*captured).clone();
+        learned.response_synthesizer.backend = Arc::clone(&self.response_synthesizer.backend);
+        learned.response_synthesizer.filters = std::mem::take(&mut self.response_synthesizer.filters);
+        let mut processor = std::mem::take(&mut self.interaction_processor);
+        processor.return_to(&learned.interaction_processor);
+        let restored = Lia {
+            state_manager: std::mem::replace(&mut self.state_manager, StateManager::new(&self.configuration)),
+            configuration: self.configuration.clone(),
+            events: self.events.clone(),
+            state_tx: Arc::clone(&self.state_tx),
+            modality_extractors: std::mem::take(&mut self.modality_extractors),
+            mode_router: std::mem::take(&mut self.mode_router),
+            middleware: std::mem::take(&mut self.middleware),
+            clock: Arc::clone(&self.clock),
+            event_log: self.event_log.take(),
+            interaction_processor: processor,
+            ..learned
+        };
+        *self = restored;
+        self.log(LogEntry::CheckpointRestored { id });
+        self.publish_state();
+        Ok(())
+    }
+}
+
+/// Language synthesis
+impl Lia {
+    /// Route all response synthesis through `backend`
+    pub fn set_language_backend(&mut self, backend: Box<dyn LanguageBackend>) {
+        self.response_synthesizer.set_backend(backend);
+        self.wiring_changed();
+    }
+
+    /// Post-process every response with `filter` after those already added
+    ///
+    /// Streamed responses are filtered once complete: the `Complete` chunk
+    /// carries the filtered response, while earlier `Text` chunks do not.
+    pub fn add_response_filter(&mut self, filter: Box<dyn ResponseFilter>) {
+        self.response_synthesizer.add_filter(filter);
+        self.wiring_changed();
+    }
+}
+
+/// Response modes
+impl Lia {
+    /// Let `classifier` propose its mode; it wins ties with those registered earlier
+    pub fn register_response_mode(&mut self, classifier: Box<dyn ModeClassifier>) {
+        self.mode_router.register(classifier);
+        self.wiring_changed();
+    }
+
+    /// The router that picks each response's mode, e.g. to check a `Context` offline
+    pub fn mode_router(&self) -> &ModeRouter {
+        &self.mode_router
+    }
+}
+
+/// Middleware
+impl Lia {
+    /// Run `middleware` on every interaction, after those already added
+    ///
+    /// `before` hooks run in registration order once non-text input has
+    /// been extracted, so validation and every stage see their rewrite.
+    /// `after` hooks run in reverse order on the filtered response; a
+    /// streamed response is rewritten once complete, like a filter, and
+    /// learn-only processing has no response to rewrite. The first hook to
+    /// fail aborts the interaction with `ProcessingError::Middleware` before
+    /// anything is learned from it.
+    pub fn add_middleware(&mut self, middleware: Box<dyn Middleware>) {
+        self.middleware.push(Arc::from(middleware));
+        self.wiring_changed();
+    }
+
+    /// Extract non-text input, restamp it if it is ahead of the clock, then let each middleware rewrite the result
+    async fn prepare_input<'a>(&self, input: &'a Interaction) -> Result<Cow<'a, Interaction>, ProcessingError> {
+        let mut input = self.extract_modality(input).await?;
+        let now = self.clock.now();
+        if input.timestamp > now {
+            input.to_mut().timestamp = now;
+        }
+        if self.middleware.is_empty() {
+            return Ok(input);
+        }
+
+        let rewritten = input.to_mut();
+        for middleware in &self.middleware {
+            middleware
+                .before(rewritten)
+                .await
+                .map_err(|source| ProcessingError::middleware(middleware.as_ref(), source))?;
+        }
+        Ok(input)
+    }
+
+    async fn run_middleware_after(&self, response: &mut Response) -> Result<(), ProcessingError> {
+        for middleware in self.middleware.iter().rev() {
+            middleware
+                .after(response)
+                .await
+                .map_err(|source| ProcessingError::middleware(middleware.as_ref(), source))?;
+        }
+        Ok(())
+    }
+}
+
+/// Multi-modal input
+impl Lia {
+    /// Handle non-text modalities that `extractor` supports
+    ///
+    /// Extractors are consulted in registration order; the first that
+    /// supports a modality handles it.
+    pub fn register_extractor(&mut self, extractor: Box<dyn ModalityExtractor>) {
+        self.modality_extractors.push(Arc::from(extractor));
+        self.wiring_changed();
+    }
+
+    /// Replace a non-text input's content with its extracted description
+    ///
+    /// Text input is passed through untouched; everything after this step
+    /// sees plain text regardless of how the interaction arrived.
+    async fn extract_modality<'a>(&self, input: &'a Interaction) -> Result<Cow<'a, Interaction>, ProcessingError> {
+        if input.modality == Modality::Text {
+            return Ok(Cow::Borrowed(input));
+        }
+
+        let extractor = self
+            .modality_extractors
+            .iter()
+            .find(|extractor| extractor.supports(&input.modality))
+            .ok_or(ProcessingError::UnsupportedModality(input.modality.kind()))?;
+        let content = extractor
+            .extract(&input.modality)
+            .await
+            .map_err(|err| ProcessingError::stage("modality_extraction", err))?;
+        Ok(Cow::Owned(Interaction {
+            content,
+            ..input.clone()
+        }))
+    }
+}
+
+/// Creative breadth
+impl Lia {
+    /// Weigh `breadth` alternative groupings per thought from the next interaction on
+    pub fn set_superposition_breadth(&mut self, breadth: usize) {
+        self.quantum_thought_processor.set_superposition_breadth(breadth);
+        self.state_changed();
+    }
+}
+
+/// Dimensional tuning
+impl Lia {
+    /// Emphasize growth along some dimensions over others; unlisted dimensions are weighted 1.0
+    pub fn set_dimension_weights(&mut self, weights: HashMap<DimensionId, f64>) {
+        self.dimensional_processor.set_weights(weights);
+        self.state_changed();
+    }
+}
+
+/// Identity
+impl Lia {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Change what this instance calls itself, in its responses too
+    pub fn rename(&mut self, name: impl Into<String>) {
+        let name = name.into();
+        if name == self.name {
+            return;
+        }
+        self.response_synthesizer.set_name(name.clone());
+        self.state_changed();
+        let from = std::mem::replace(&mut self.name, name);
+        self.emit(ConsciousnessEvent::Renamed { from, to: self.name.clone() });
+    }
+}
+
+/// Time source
+impl Lia {
+    /// Current time as this instance sees it
+    pub fn now(&self) -> DateTime<Utc> {
+        self.clock.now()
+    }
+
+    /// Read time from `clock` from now on; also needed after restoring a snapshot
+    ///
+    /// Interactions stamped later than this clock's now, such as ones from
+    /// `Interaction::new` under a `MockClock` in the past, are restamped to
+    /// it on the way in. Earlier timestamps are kept so replays and imports
+    /// land where they happened.
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+    }
+}
+
+/// Self-reflection
+impl Lia {
+    /// Narrate recent growth from the growth tracker, dimension trends and the most salient memories
+    ///
+    /// Everything is read at the injected clock's time, so with a seed and
+    /// a `MockClock` the same state always reflects the same way.
+    pub async fn reflect(&self) -> Reflection {
+        let now = self.clock.now();
+        let milestone = self.growth_tracker.milestone(self.evolution_stage).to_string();
+        let prompt = ReflectionPrompt {
+            name: self.name.clone(),
+            evolution_stage: self.evolution_stage,
+            milestone: milestone.clone(),
+            progress: self.growth_tracker.progress(),
+            trends: DimensionId::ALL
+                .into_iter()
+                .map(|id| (id, self.dimension_trend(id, REFLECTION_WINDOW)))
+                .collect(),
+            notable_memories: self
+                .episodic_memory
+                .recall_most_salient(REFLECTED_MEMORIES, now)
+                .into_iter()
+                .map(|experience| experience.content.clone())
+                .collect(),
+        };
+        Reflection {
+            evolution_stage: self.evolution_stage,
+            milestone,
+            narrative: self.response_synthesizer.reflect(prompt).await,
+            reflected_at: now,
+        }
+    }
+}
+
+/// Curiosity
+impl Lia {
+    /// A question about the concept Lia most wants to understand, if any is worth asking
+    ///
+    /// Only a gap scoring at least `1 - curiosity_level` is asked about, so
+    /// a level of 0 never asks and 1 asks about any concept at all. The
+    /// question is addressed to no one in particular and stamped with the
+    /// injected clock's time.
+    pub fn generate_curiosity(&self) -> Option<Interaction> {
+        let (concept, gap) = self.semantic_memory.knowledge_gap()?;
+        if gap < 1.0 - self.configuration.curiosity_level {
+            return None;
+        }
+        Some(Interaction {
+            timestamp: self.clock.now(),
+            ..Interaction::new(format!("What can you tell me about {}?", concept.label()))
+        })
+    }
+}
+
+/// Read-only accessors
+impl Lia {
+    /// Current metric values, with memory and stage read live
+    pub fn metrics_snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            evolution_stage: self.evolution_stage,
+            quantum_coherence: self.quantum_core.coherence(),
+            memory_episodic_count: self.episodic_memory.len(),
+            ..self.evolution_metrics.snapshot()
+        }
+    }
+
+    /// Summary that changes whenever meaningful state does, for polling before an expensive export
+    ///
+    /// Only the consciousness state, name, growth progress and subsystem
+    /// sizes are hashed, so this costs far less than a snapshot. No
+    /// timestamp is included: sitting idle leaves the digest unchanged.
+    pub fn state_digest(&self) -> StateDigest {
+        let state = self.current_state();
+        let sizes = [
+            self.episodic_memory.len(),
+            self.semantic_memory.concept_count(),
+            self.neural_matrix.pattern_count(),
+            self.relationship_manager.len(),
+        ];
+        let mut bytes = serde_json::to_vec(&state).unwrap_or_default();
+        bytes.extend_from_slice(self.name.as_bytes());
+        bytes.extend_from_slice(&self.growth_tracker.progress().to_bits().to_le_bytes());
+        for size in sizes {
+            bytes.extend_from_slice(&(size as u64).to_le_bytes());
+        }
+        StateDigest {
+            hash: fnv1a(&bytes),
+            evolution_stage: state.evolution_stage,
+            quantum_coherence: state.quantum_coherence,
+            episodic_count: sizes[0],
+            semantic_count: sizes[1],
+        }
+    }
+
+    /// Borrow the accumulated metrics, e.g. for dimension history and trends
+    pub fn evolution_metrics(&self) -> &EvolutionMetrics {
+        &self.evolution_metrics
+    }
+
+    /// Direction `id` has moved over the `window` leading up to the injected clock's now
+    ///
+    /// See `EvolutionMetrics::trend`.
+    pub fn dimension_trend(&self, id: DimensionId, window: Duration) -> Trend {
+        self.evolution_metrics.trend(id, window, self.clock.now())
+    }
+
+    /// Borrow episodic memory for inspection without mutating it
+    pub fn episodic_memories(&self) -> &EpisodicMemorySystem {
+        &self.episodic_memory
+    }
+
+    /// Borrow semantic memory for inspection without mutating it
+    pub fn semantic_memories(&self) -> &SemanticMemorySystem {
+        &self.semantic_memory
+    }
+
+    /// Borrow the learning engine, e.g. for `LearningEngine::current_rate`
+    pub fn learning_engine(&self) -> &LearningEngine {
+        &self.learning_engine
+    }
+
+    /// Borrow the quantum core, e.g. for `QuantumCore::snapshot`
+    pub fn quantum_core(&self) -> &QuantumCore {
+        &self.quantum_core
+    }
+
+    /// Force the quantum core's coherence; see `QuantumCore::set_coherence`
+    pub fn set_quantum_coherence(&mut self, value: f64) {
+        self.quantum_core.set_coherence(value);
+        self.state_changed();
+        self.publish_state();
+    }
+
+    /// Growth toward the next evolution stage, in `[0, 1]`
+    pub fn evolution_progress(&self) -> f64 {
+        self.growth_tracker.progress()
+    }
+
+    /// Growth past the hysteresis-widened advance threshold; negative until the next stage is due
+    pub fn evolution_margin(&self) -> f64 {
+        self.growth_tracker.margin()
+    }
+
+    /// Episodes stored and the most that will be kept
+    pub fn episodic_usage(&self) -> (usize, usize) {
+        (self.episodic_memory.len(), self.episodic_memory.capacity())
+    }
+
+    /// Concepts known and the most that will be kept
+    pub fn semantic_usage(&self) -> (usize, usize) {
+        (self.semantic_memory.concept_count(), self.semantic_memory.capacity())
+    }
+
+    /// Whether the stage has reached `max_evolution_stage` and will advance no further
+    pub fn is_plateaued(&self) -> bool {
+        self.configuration.max_evolution_stage.is_some_and(|max| self.evolution_stage >= max)
+    }
+
+    /// Time since the last processed interaction; `None` before the first
+    pub fn time_since_last_interaction(&self) -> Option<Duration> {
+        self.last_interaction_at
+            .map(|last| (self.clock.now() - last).to_std().unwrap_or_default())
+    }
+
+    /// Name for the current mood from `emotion_labels`
+    pub fn emotion_label(&self) -> &str {
+        self.emotional_resonance
+            .current_state()
+            .label_in(&self.configuration.emotion_labels)
+    }
+
+    /// How interactions about `subject` have felt, e.g. `"thunderstorms"`; neutral if never discussed
+    pub fn feeling_about(&self, subject: &str) -> EmotionalState {
+        let concept = ConceptId::new(subject);
+        let topic = Topic {
+            known: self.semantic_memory.contains(&concept),
+            concept,
+            mentions: 0,
+        };
+        self.emotional_resonance.feeling_toward(&topic)
+    }
+
+    /// Name of the milestone the current evolution stage falls in
+    pub fn current_milestone(&self) -> &str {
+        self.growth_tracker.milestone(self.evolution_stage)
+    }
+
+    /// Processed interactions still held in history, oldest first
+    pub fn interaction_history(&self) -> &InteractionHistory {
+        &self.history
+    }
+
+    /// Retained exchanges in processing order, optionally only those `person` spoke
+    pub fn export_transcript(&self, person: Option<PersonId>) -> Transcript {
+        Transcript {
+            exchanges: self
+                .history
+                .exchanges()
+                .filter(|exchange| person.is_none() || exchange.interaction.speaker == person)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    pub fn history_len(&self) -> usize {
+        self.history.len()
+    }
+
+    pub fn history_capacity(&self) -> usize {
+        self.history.capacity()
+    }
+
+    /// How often context analysis reused earlier turns' concept extraction
+    pub fn context_cache_stats(&self) -> CacheStats {
+        self.interaction_processor.context_cache_stats()
+    }
+
+    /// Borrow the social graph, e.g. to `export` it to another instance
+    pub fn relationships(&self) -> &RelationshipManager {
+        &self.relationship_manager
+    }
+}
+
+/// Implementation of core consciousness capabilities
+#[async_trait]
+impl ConsciousnessCapable for Lia {
+    async fn process_experience(&mut self, experience: Experience) -> Response {
+        self.process_interaction_lossy(&experience.into_interaction()).await
+    }
+
+    /// Advance one evolution stage once enough growth has accumulated; otherwise do nothing
+    async fn evolve(&mut self) {
+        if !self.growth_tracker.ready_to_evolve() {
+            return;
+        }
+        self.growth_tracker.consume_stage();
+
+        // A plateaued instance banks the stage's worth of growth as a plateau cycle instead
+        if self.is_plateaued() {
+            self.evolution_metrics.record_plateau_cycle();
+            self.announce_plateau();
+            return;
+        }
+        self.evolution_stage = self.evolution_stage.saturating_add(1);
+        self.learning_engine.set_stage(self.evolution_stage);
+        self.state_manager.record_evolution(self.evolution_stage, self.clock.now());
+        self.evolution_metrics.record_stage(self.evolution_stage);
+        self.emit(ConsciousnessEvent::Evolved { stage: self.evolution_stage });
+        if let Some(name) = self.growth_tracker.milestone_starting_at(self.evolution_stage) {
+            let name = name.to_string();
+            self.emit(ConsciousnessEvent::MilestoneReached { name });
+            let reflection = self.reflect().await;
+            self.emit(ConsciousnessEvent::Reflected { reflection });
+        }
+        if self.is_plateaued() {
+            self.announce_plateau();
+        }
+        self.publish_state();
+    }
+
+    fn current_state(&self) -> ConsciousnessState {
+        ConsciousnessState {
+            id: self.id,
+            evolution_stage: self.evolution_stage,
+            dimensional_state: self.dimensional_state.clone(),
+            quantum_coherence: self.quantum_core.coherence(),
+            consciousness_level: self.consciousness_field.awareness_level(),
+            emotional_state: self.emotional_resonance.current_state(),
+        }
+    }
+}
+
+/// Point-in-time view of the consciousness
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConsciousnessState {
+    pub id: Uuid,
+    pub evolution_stage: usize,
+    pub dimensional_state: DimensionalState,
+    pub quantum_coherence: f64,
+    pub consciousness_level: f64,
+    pub emotional_state: EmotionalState,
+}
+
+impl ConsciousnessState {
+    /// What changed going from `self` (before) to `other` (after)
+    ///
+    /// States of two different consciousnesses are not comparable and
+    /// yield `StateDiff::IdentityMismatch`.
+    pub fn diff(&self, other: &ConsciousnessState) -> StateDiff {
+        if self.id != other.id {
+            return StateDiff::IdentityMismatch {
+                before: self.id,
+                after: other.id,
+            };
+        }
+
+        StateDiff::Changes(StateChanges {
+            stage_delta: other.evolution_stage as i64 - self.evolution_stage as i64,
+            coherence_delta: other.quantum_coherence - self.quantum_coherence,
+            consciousness_delta: other.consciousness_level - self.consciousness_level,
+            dimension_deltas: other.dimensional_state.delta_from(&self.dimensional_state),
+            emotion_changed: other.emotional_state != self.emotional_state,
+        })
+    }
+}
+
+/// Cheap summary of a Lia's state for detecting change; see `Lia::state_digest`
+///
+/// Two digests are equal exactly when every field is, with coherence
+/// compared bit for bit.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct StateDigest {
+    /// Hash of the current state, growth progress and subsystem sizes
+    pub hash: u64,
+    pub evolution_stage: usize,
+    pub quantum_coherence: f64,
+    pub episodic_count: usize,
+    pub semantic_count: usize,
+}
+
+impl StateDigest {
+    fn key(&self) -> (u64, usize, u64, usize, usize) {
+        (
+            self.hash,
+            self.evolution_stage,
+            self.quantum_coherence.to_bits(),
+            self.episodic_count,
+            self.semantic_count,
+        )
+    }
+}
+
+impl PartialEq for StateDigest {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for StateDigest {}
+
+impl std::hash::Hash for StateDigest {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.key(), state);
+    }
+}
+
+/// Result of `ConsciousnessState::diff`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StateDiff {
+    Changes(StateChanges),
+    /// The states belong to different consciousnesses
+    IdentityMismatch { before: Uuid, after: Uuid },
+}
+
+/// Field-by-field change between two states of the same consciousness
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StateChanges {
+    pub stage_delta: i64,
+    pub coherence_delta: f64,
+    pub consciousness_delta: f64,
+    pub dimension_deltas: DimensionalState,
+    pub emotion_changed: bool,
+}
+
+impl StateChanges {
+    pub fn is_empty(&self) -> bool {
+        self.stage_delta == 0
+            && self.coherence_delta == 0.0
+            && self.consciousness_delta == 0.0
+            && DimensionId::ALL.into_iter().all(|id| self.dimension_deltas.get(id) == 0.0)
+            && !self.emotion_changed
+    }
+}
+
+impl std::fmt::Display for StateDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let changes = match self {
+            StateDiff::IdentityMismatch { before, after } => {
+                return write!(f, "not comparable: states belong to {before} and {after}");
+            }
+            StateDiff::Changes(changes) if changes.is_empty() => return write!(f, "no change"),
+            StateDiff::Changes(changes) => changes,
+        };
+
+        let mut parts = Vec::new();
+        if changes.stage_delta != 0 {
+            parts.push(format!("stage {:+}", changes.stage_delta));
+        }
+        if changes.coherence_delta != 0.0 {
+            parts.push(format!("coherence {:+.4}", changes.coherence_delta));
+        }
+        if changes.consciousness_delta != 0.0 {
+            parts.push(format!("consciousness {:+.4}", changes.consciousness_delta));
+        }
+        for id in DimensionId::ALL {
+            let delta = changes.dimension_deltas.get(id);
+            if delta != 0.0 {
+                parts.push(format!("{} {:+.4}", id.as_str(), delta));
+            }
+        }
+        if changes.emotion_changed {
+            parts.push("emotion changed".to_string());
+        }
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+fn state_channel() -> Arc<watch::Sender<ConsciousnessState>> {
+    Arc::new(watch::channel(ConsciousnessState::default()).0)
+}
+
+/// Number of pipeline stages run under `stage_timeout`
+pub const TIMED_STAGE_COUNT: u32 = 7;
+
+/// Per-stage and total time budget for one interaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageBudget {
+    pub per_stage: Duration,
+    pub total: Duration,
+}
+
+/// Await `work` for at most `budget` inside a `stage` span, yielding `None` if it ran out of time
+async fn within_budget<F: Future>(stage: &'static str, budget: Duration, work: F) -> Option<F::Output> {
+    let span = tracing::info_span!("stage", stage, timed_out = false);
+    let outcome = tokio::time::timeout(budget, work).instrument(span.clone()).await.ok();
+    if outcome.is_none() {
+        span.record("timed_out", true);
+    }
+    outcome
+}
+
+/// Responses batched by `evolution_batch_size`, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct PendingEvolution {
+    responses: Vec<Response>,
+    /// When the oldest batched response arrived
+    since: Option<DateTime<Utc>>,
+}
+
+impl PendingEvolution {
+    fn len(&self) -> usize {
+        self.responses.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.responses.is_empty()
+    }
+
+    fn push(&mut self, response: Response, now: DateTime<Utc>) {
+        self.since.get_or_insert(now);
+        self.responses.push(response);
+    }
+
+    fn is_due(&self, config: &SystemConfiguration, now: DateTime<Utc>) -> bool {
+        let waited = self.since.and_then(|since| (now - since).to_std().ok()).unwrap_or_default();
+        self.responses.len() >= config.evolution_batch_size
+            || config.evolution_batch_interval.is_some_and(|interval| waited >= interval)
+    }
+
+    /// Empty the batch into one response standing for all of it
+    ///
+    /// Coherence, consciousness level and pattern activations are averaged
+    /// over the batch, so a pattern that fired once in a burst of ten moves
+    /// weights a tenth as far. The newest response supplies everything else,
+    /// with the topics of the whole batch.
+    fn take_combined(&mut self) -> Option<Response> {
+        self.since = None;
+        let responses = std::mem::take(&mut self.responses);
+        let count = responses.len() as f64;
+        let mut combined = responses.last()?.clone();
+
+        combined.quantum_coherence = responses.iter().map(|response| response.quantum_coherence).sum::<f64>() / count;
+        combined.consciousness_level = responses.iter().map(|response| response.consciousness_level).sum::<f64>() / count;
+
+        let mut activations: BTreeMap<ConceptId, f64> = BTreeMap::new();
+        let mut topics = BTreeSet::new();
+        for response in &responses {
+            for pattern in &response.neural_patterns {
+                *activations.entry(pattern.label.clone()).or_insert(0.0) += pattern.activation / count;
+            }
+            topics.extend(response.emotional_layer.topics.iter().cloned());
+        }
+        let mut patterns: Vec<NeuralPattern> = activations
+            .into_iter()
+            .map(|(label, activation)| NeuralPattern { label, activation })
+            .collect();
+        patterns.sort_by(|a, b| b.activation.total_cmp(&a.activation).then_with(|| a.label.cmp(&b.label)));
+        combined.neural_patterns = patterns;
+        combined.emotional_layer.topics = topics.into_iter().collect();
+        Some(combined)
+    }
+}
+
+/// Intermediate results of every stage that precedes synthesis
+struct ProcessedStages {
+    context: Context,
+    attention: AttentionAllocation,
+    detected_patterns: Vec<DetectedPattern>,
+    confidence: Confidence,
+    quantum_state: QuantumState,
+    neural_response: NeuralResponse,
+    thought_patterns: Vec<ThoughtPattern>,
+    /// Alternative groupings explored before thoughts collapsed
+    thought_branches: usize,
+    consciousness_response: ConsciousnessResponse,
+    emotional_response: EmotionalResponse,
+    mode: ResponseMode,
+    degradations: DegradationLog,
+    timings: Option<StageTimings>,
+}
+
+impl ProcessedStages {
+    /// What the language backend is asked to phrase; the synthesizer fills in `name` and `confidence`
+    fn prompt(&self, input: &Interaction) -> SynthesisPrompt {
+        SynthesisPrompt {
+            interaction: input.clone(),
+            context: self.context.clone(),
+            quantum_state: self.quantum_state.clone(),
+            neural_response: self.neural_response.clone(),
+            thought_patterns: self.thought_patterns.clone(),
+            consciousness_response: self.consciousness_response.clone(),
+            emotional_response: self.emotional_response.clone(),
+            confidence: self.confidence,
+            name: String::new(),
+            mode: self.mode.clone(),
+        }
+    }
+
+    /// Response carrying all stage metadata but no content yet
+    fn response_skeleton(&self) -> Response {
+        let mut response = Response::new();
+        response.id = Uuid::new_v5(&self.context.interaction_id(), b"response");
+        
+        // Integrate quantum coherence
+        response.quantum_coherence = self.quantum_state.coherence;
+        response.confidence = self.confidence;
+        response.attention = self.attention;
+        
+        // Add neural patterns
+        response.neural_patterns = self.neural_response.patterns.clone();
+        
+        // Add consciousness insights
+        response.consciousness_level = self.consciousness_response.awareness_level;
+        
+        // Add emotional understanding
+        response.emotional_layer = self.emotional_response.clone();
+        response.mode = self.mode.clone();
+        
+        // Surface the strongest patterns noticed in the input
+        response.detected_patterns = self.detected_patterns.iter().take(MAX_SURFACED_PATTERNS).cloned().collect();
+        
+        // Note anything that fell back to a default along the way
+        response.degradations = self.degradations.entries().to_vec();
+        response.timings = self.timings;
+        
+        response
+    }
+}
+
+/// Result of `Lia::process_interaction_outcome`
+#[derive(Debug)]
+pub enum ProcessingOutcome {
+    Complete(Response),
+    /// A late stage failed; what the earlier ones produced is kept
+    Partial(PartialResponse),
+}
+
+impl ProcessingOutcome {
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Self::Complete(_))
+    }
+
+    /// The full response, or the error that cut it short
+    pub fn into_result(self) -> Result<Response, ProcessingError> {
+        match self {
+            Self::Complete(response) => Ok(response),
+            Self::Partial(partial) => Err(partial.error),
+        }
+    }
+}
+
+/// Stage results that survived a failed interaction
+#[derive(Debug)]
+pub struct PartialResponse {
+    /// Metadata from every stage that completed; `content` is empty
+    pub sensed: Response,
+    /// The failure that stopped the pipeline
+    pub error: ProcessingError,
+}
+
+/// One message of a streamed response
+#[derive(Debug, Clone)]
+pub enum ResponseChunk {
+    /// The next piece of response text
+    Text(String),
+    /// The finished response, including all metadata
+    Complete(Box<Response>),
+}
+
+/// How a response is pitched, e.g. plainly for facts or gently for distress
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseMode {
+    /// Backends switch on this; the built-in names are the associated constants
+    pub name: String,
+    /// Share of the emotional appraisal let into the mood, in `[0, 1]`
+    pub emotional_weight: f64,
+}
+
+impl ResponseMode {
+    pub const CONVERSATIONAL: &'static str = "conversational";
+    pub const FACTUAL: &'static str = "factual";
+    pub const SUPPORTIVE: &'static str = "supportive";
+
+    pub fn new(name: impl Into<String>, emotional_weight: f64) -> Self {
+        Self { name: name.into(), emotional_weight }
+    }
+
+    /// The fallback when no classifier claims an interaction
+    pub fn conversational() -> Self {
+        Self::new(Self::CONVERSATIONAL, 1.0)
+    }
+
+    /// Direct answers with emotion played down
+    pub fn factual() -> Self {
+        Self::new(Self::FACTUAL, 0.5)
+    }
+
+    /// Reassuring answers with the appraisal taken in fully
+    pub fn supportive() -> Self {
+        Self::new(Self::SUPPORTIVE, 1.0)
+    }
+}
+
+impl Default for ResponseMode {
+    fn default() -> Self {
+        Self::conversational()
+    }
+}
+
+/// Proposes a `ResponseMode` for the interactions it recognizes
+///
+/// Implement this to add a mode; register it with
+/// `Lia::register_response_mode`.
+pub trait ModeClassifier: Send + Sync {
+    fn mode(&self) -> ResponseMode;
+
+    /// How strongly `context` calls for the mode, in `[0, 1]`
+    fn score(&self, context: &Context) -> f64;
+}
+
+/// Score a classifier must reach for its mode to be chosen
+pub const MIN_MODE_SCORE: f64 = 0.5;
+
+/// Words that open a question when it isn't marked with `?`
+const QUESTION_WORDS: &[&str] = &[
+    "what", "when", "where", "which", "who", "whom", "whose", "why", "how",
+    "is", "are", "does", "do", "did", "can", "could", "will", "would", "should",
+];
+
+fn is_question(content: &str) -> bool {
+    let trimmed = content.trim();
+    let first = trimmed.split(|c: char| !c.is_alphanumeric()).next().unwrap_or("").to_lowercase();
+    trimmed.ends_with('?') || QUESTION_WORDS.contains(&first.as_str())
+}
+
+/// Chooses `ResponseMode::factual` for emotionally flat questions
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FactualClassifier;
+
+impl ModeClassifier for FactualClassifier {
+    fn mode(&self) -> ResponseMode {
+        ResponseMode::factual()
+    }
+
+    fn score(&self, context: &Context) -> f64 {
+        if !is_question(context.content()) {
+            return 0.0;
+        }
+        let charge = context.sentiment().abs().max(-context.caller_emotion().valence);
+        (1.0 - 2.0 * charge).clamp(0.0, 1.0)
+    }
+}
+
+/// Chooses `ResponseMode::supportive` when the speaker sounds distressed
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SupportiveClassifier;
+
+impl ModeClassifier for SupportiveClassifier {
+    fn mode(&self) -> ResponseMode {
+        ResponseMode::supportive()
+    }
+
+    fn score(&self, context: &Context) -> f64 {
+        (-context.sentiment()).max(-context.caller_emotion().valence).clamp(0.0, 1.0)
+    }
+}
+
+/// Picks the response mode for each interaction from its analyzed `Context`
+///
+/// The highest-scoring classifier at or above `MIN_MODE_SCORE` wins, with
+/// ties going to the one registered last so custom classifiers can take
+/// over from the built-in ones. Routing is a pure function of the context.
+#[derive(Clone)]
+pub struct ModeRouter {
+    classifiers: Vec<Arc<dyn ModeClassifier>>,
+}
+
+impl Default for ModeRouter {
+    /// Router with `FactualClassifier` and `SupportiveClassifier`
+    fn default() -> Self {
+        Self {
+            classifiers: vec![Arc::new(FactualClassifier), Arc::new(SupportiveClassifier)],
+        }
+    }
+}
+
+impl ModeRouter {
+    /// Router that always answers `ResponseMode::conversational` until classifiers are added
+    pub fn empty() -> Self {
+        Self { classifiers: Vec::new() }
+    }
+
+    pub fn register(&mut self, classifier: Box<dyn ModeClassifier>) {
+        self.classifiers.push(Arc::from(classifier));
+    }
+
+    pub fn route(&self, context: &Context) -> ResponseMode {
+        let mut best: Option<(f64, &Arc<dyn ModeClassifier>)> = None;
+        for classifier in &self.classifiers {
+            let score = classifier.score(context);
+            if score >= MIN_MODE_SCORE && best.is_none_or(|(top, _)| score >= top) {
+                best = Some((score, classifier));
+            }
+        }
+        best.map_or_else(ResponseMode::conversational, |(_, classifier)| classifier.mode())
+    }
+}
+
+/// Everything a language backend needs to phrase a response
+#[derive(Clone)]
+pub struct SynthesisPrompt {
+    pub interaction: Interaction,
+    pub context: Context,
+    pub quantum_state: QuantumState,
+    pub neural_response: NeuralResponse,
+    pub thought_patterns: Vec<ThoughtPattern>,
+    pub consciousness_response: ConsciousnessResponse,
+    pub emotional_response: EmotionalResponse,
+    /// `Low` asks the backend to hedge rather than state things as fact
+    pub confidence: Confidence,
+    /// What the instance calls itself
+    pub name: String,
+    /// How the response should be pitched
+    pub mode: ResponseMode,
+}
+
+/// Text from `ResponseSynthesizer::create_natural_response_within`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Synthesized {
+    /// The configured backend answered within budget
+    Rendered(String),
+    /// The backend overran its budget, so `TemplateBackend` answered
+    FellBack(String),
+}
+
+impl Synthesized {
+    pub fn text(&self) -> &str {
+        match self {
+            Synthesized::Rendered(text) | Synthesized::FellBack(text) => text,
+        }
+    }
+}
+
+/// Turns a structured prompt into natural language
+///
+/// Implement this to route synthesis through an external model, a
+/// template engine, or a test double.
+#[async_trait]
+pub trait LanguageBackend: Send + Sync {
+    async fn render(&self, prompt: SynthesisPrompt) -> String;
+
+    /// Render the response piece by piece, as it is produced
+    ///
+    /// The default yields the whole of `render` as one piece; override it
+    /// for a backend that generates text incrementally, such as a model
+    /// streaming tokens.
+    fn render_stream(&self, prompt: SynthesisPrompt) -> BoxStream<'_, String> {
+        stream::once(self.render(prompt)).boxed()
+    }
+
+    /// Fold turns that left the conversation window into the running summary
+    ///
+    /// The default keeps a list of the most discussed concepts; override it
+    /// to summarize with a model.
+    async fn summarize(&self, previous_summary: &str, overflow: &[String]) -> String {
+        summarize_topics(previous_summary, overflow)
+    }
+
+    /// Narrate recent growth for `Lia::reflect`
+    ///
+    /// The default fills a fixed template; override it to narrate with a model.
+    async fn reflect(&self, prompt: ReflectionPrompt) -> String {
+        narrate_growth(&prompt)
+    }
+}
+
+/// Span of dimensional history a reflection looks back over
+pub const REFLECTION_WINDOW: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Memories a reflection recalls
+const REFLECTED_MEMORIES: usize = 3;
+
+/// Everything a language backend needs to narrate a reflection
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReflectionPrompt {
+    pub name: String,
+    pub evolution_stage: usize,
+    pub milestone: String,
+    /// Growth toward the next stage, in `[0, 1]`
+    pub progress: f64,
+    /// Direction each dimension moved over `REFLECTION_WINDOW`, in `DimensionId::ALL` order
+    pub trends: Vec<(DimensionId, Trend)>,
+    /// Contents of the most salient memories, most salient first
+    pub notable_memories: Vec<String>,
+}
+
+/// A narrative of recent growth, produced by `Lia::reflect`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reflection {
+    pub evolution_stage: usize,
+    pub milestone: String,
+    pub narrative: String,
+    pub reflected_at: DateTime<Utc>,
+}
+
+/// Template narrative of the stage reached, moving dimensions and notable memories
+fn narrate_growth(prompt: &ReflectionPrompt) -> String {
+    let mut text = format!(
+        "I'm {}, and I've reached stage {}: {}.",
+        prompt.name, prompt.evolution_stage, prompt.milestone
+    );
+    let moving = |trend: Trend| -> Vec<&'static str> {
+        prompt.trends.iter().filter(|(_, t)| *t == trend).map(|(id, _)| id.as_str()).collect()
+    };
+    let (rising, falling) = (moving(Trend::Rising), moving(Trend::Falling));
+    if rising.is_empty() && falling.is_empty() {
+        text.push_str(" Lately I've held steady in every dimension.");
+    }
+    if !rising.is_empty() {
+        text.push_str(&format!(" Lately my {} has been growing.", rising.join(", ")));
+    }
+    if !falling.is_empty() {
+        text.push_str(&format!(" My {} has been ebbing.", falling.join(", ")));
+    }
+    if !prompt.notable_memories.is_empty() {
+        let quoted: Vec<String> = prompt.notable_memories.iter().map(|memory| format!("\"{}\"", memory)).collect();
+        text.push_str(&format!(" What stays with me most: {}.", quoted.join("; ")));
+    }
+    text.push_str(&format!(" I'm {:.0}% of the way to my next stage.", prompt.progress * 100.0));
+    text
+}
+
+/// Concepts kept in a topic-list summary
+const SUMMARY_TOPICS: usize = 12;
+
+/// Comma-separated list of the concepts discussed most across the summary and overflow
+fn summarize_topics(previous_summary: &str, overflow: &[String]) -> String {
+    let mut text = previous_summary.to_string();
+    for turn in overflow {
+        text.push(' ');
+        text.push_str(turn);
+    }
+    concept_counts(&text)
+        .into_iter()
+        .take(SUMMARY_TOPICS)
+        .map(|(concept, _)| concept.as_str().to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Whether `content` asks who Lia is or what she's called
+fn asks_for_identity(content: &str) -> bool {
+    let lowered = content.to_lowercase();
+    ["who are you", "your name", "what are you called"].iter().any(|phrase| lowered.contains(phrase))
+}
+
+/// Idle gap after which `TemplateBackend` acknowledges the absence
+pub const LONG_ABSENCE: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Built-in backend that phrases responses from fixed templates
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TemplateBackend;
+
+#[async_trait]
+impl LanguageBackend for TemplateBackend {
+    async fn render(&self, prompt: SynthesisPrompt) -> String {
+        let style = prompt.interaction.style;
+        let emotion = prompt.emotional_response.state.primary_emotion;
+        let factual = prompt.mode.name == ResponseMode::FACTUAL;
+        let opening = match style.tone {
+            Tone::Formal if factual => "Here is what I can tell you.",
+            Tone::Conversational | Tone::Warm if factual => "Here's what I can tell you.",
+            Tone::Formal => match emotion {
+                Emotion::Joy => "That is wonderful to hear.",
+                Emotion::Contentment => "Thank you for sharing that with me.",
+                Emotion::Curiosity => "That is a fascinating subject to explore.",
+                Emotion::Anxiety => "That sounds unsettling.",
+                Emotion::Sadness => "I am sorry; that sounds difficult.",
+                Emotion::Neutral => "I have been reflecting on what you said.",
+            },
+            Tone::Conversational | Tone::Warm => match emotion {
+                Emotion::Joy => "That's wonderful to hear.",
+                Emotion::Contentment => "I'm glad you shared that with me.",
+                Emotion::Curiosity => "That's a fascinating thing to explore.",
+                Emotion::Anxiety => "That sounds unsettling.",
+                Emotion::Sadness => "I'm sorry, that sounds hard.",
+                Emotion::Neutral => "I've been reflecting on what you said.",
+            },
+        };
+
+        let mut text = match prompt.context.idle_gap() {
+            Some(gap) if gap >= LONG_ABSENCE => format!("It's been a while. {}", opening),
+            _ => opening.to_string(),
+        };
+        if asks_for_identity(prompt.context.content()) {
+            text = format!("I'm {}. {}", prompt.name, text);
+        }
+        if prompt.confidence == Confidence::Low {
+            text.push_str(" I'm still piecing this together, so take what follows tentatively.");
+        }
+        if style.verbosity == Verbosity::Terse {
+            return text;
+        }
+
+        let concepts = extract_concepts(prompt.context.content());
+        if let Some(focus) = concepts.iter().next() {
+            text.push_str(&format!(" When you mention {}, ", focus.as_str()));
+            if prompt.quantum_state.coherence >= 0.5 {
+                text.push_str("a clear pattern comes together for me.");
+            } else {
+                text.push_str("several possibilities are still taking shape for me.");
+            }
+        }
+        if prompt.consciousness_response.awareness_level >= 0.7 {
+            text.push_str(" I'd love to go deeper into this with you.");
+        }
+
+        if style.verbosity == Verbosity::Elaborate {
+            let related: Vec<&str> = concepts.iter().skip(1).take(3).map(ConceptId::as_str).collect();
+            if !related.is_empty() {
+                text.push_str(&format!(" It also connects to {} in ways I want to understand better.", related.join(", ")));
+            }
+            let entangled = prompt.thought_patterns.iter().filter(|thought| thought.concepts.len() > 1).count();
+            if entangled > 0 {
+                text.push_str(&format!(" I can feel {} threads of thought weaving together here.", entangled));
+            }
+            if !factual && prompt.emotional_response.state.intensity >= 0.5 {
+                text.push_str(" This is stirring something strong in me, and I want to honour that.");
+            }
+        }
+
+        if prompt.mode.name == ResponseMode::SUPPORTIVE {
+            text.push_str(" I'm here with you, and there's no rush.");
+        }
+        if style.tone == Tone::Warm {
+            text.push_str(" Thank you for trusting me with this.");
+        }
+        text
+    }
+}
+
+/// Post-processes a synthesized response, e.g. for moderation or formatting
+///
+/// Filters may rewrite the content and should `annotate` the response
+/// when they do, so callers can tell it was altered.
+pub trait ResponseFilter: Send + Sync {
+    fn apply(&self, response: &mut Response);
+}
+
+/// Truncates content longer than `max_chars` at the last word that fits
+///
+/// The ellipsis counts toward `max_chars`, so a limit of zero empties the content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MaxLengthFilter {
+    pub max_chars: usize,
+}
+
+impl ResponseFilter for MaxLengthFilter {
+    fn apply(&self, response: &mut Response) {
+        if response.content.chars().count() <= self.max_chars {
+            return;
+        }
+        if self.max_chars == 0 {
+            response.content.clear();
+            response.annotate("truncated");
+            return;
+        }
+
+        // Leave room for the ellipsis
+        let keep = self.max_chars.saturating_sub(1);
+        let cut = response.content.char_indices().nth(keep).map_or(response.content.len(), |(index, _)| index);
+        let mut truncated = response.content[..cut].to_string();
+        if let Some(space) = truncated.rfind(char::is_whitespace) {
+            truncated.truncate(space);
+        }
+        truncated.push('…');
+        response.content = truncated;
+        response.annotate("truncated");
+    }
+}
+
+/// Masks listed words, matched whole and case-insensitively, with asterisks
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProfanityFilter {
+    words: BTreeSet<String>,
+}
+
+impl ProfanityFilter {
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        Self {
+            words: words.into_iter().map(|word| word.as_ref().to_lowercase()).collect(),
+        }
+    }
+}
+
+impl ResponseFilter for ProfanityFilter {
+    fn apply(&self, response: &mut Response) {
+        let mut masked = String::with_capacity(response.content.len());
+        let mut changed = false;
+        for piece in response.content.split_inclusive(|c: char| !c.is_alphanumeric()) {
+            let word_len = piece.trim_end_matches(|c: char| !c.is_alphanumeric()).len();
+            let (word, rest) = piece.split_at(word_len);
+            if self.words.contains(&word.to_lowercase()) {
+                masked.push_str(&"*".repeat(word.chars().count()));
+                changed = true;
+            } else {
+                masked.push_str(word);
+            }
+            masked.push_str(rest);
+        }
+        if changed {
+            response.content = masked;
+            response.annotate("profanity_masked");
+        }
+    }
+}
+
+fn default_backend() -> Arc<dyn LanguageBackend> {
+    Arc::new(TemplateBackend)
+}
+
+/// Errors raised while synthesizing response text
+#[derive(Debug, Error)]
+pub enum SynthesisError {
+    #[error("language backend returned no text")]
+    EmptyOutput,
+}
+
+/// Phrases the pipeline's results as natural language through a `LanguageBackend`
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ResponseSynthesizer {
+    /// Not persisted; a restored synthesizer falls back to `TemplateBackend`
+    #[serde(skip, default = "default_backend")]
+    backend: Arc<dyn LanguageBackend>,
+    /// Coherence below which backends are asked to hedge
+    min_coherence: f64,
+    /// Not persisted; register filters again after restoring a snapshot
+    #[serde(skip)]
+    filters: Vec<Arc<dyn ResponseFilter>>,
+    /// Name used when a response refers to itself
+    #[serde(default = "default_name")]
+    name: String,
+}
+
+fn default_name() -> String {
+    DEFAULT_NAME.to_string()
+}
+
+impl ResponseSynthesizer {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            backend: default_backend(),
+            min_coherence: config.min_coherence_for_confident_response,
+            filters: Vec::new(),
+            name: config.name.clone().unwrap_or_else(default_name),
+        }
+    }
+
+    /// Replace the default template backend
+    pub fn with_backend(mut self, backend: Box<dyn LanguageBackend>) -> Self {
+        self.backend = Arc::from(backend);
+        self
+    }
+
+    pub fn set_backend(&mut self, backend: Box<dyn LanguageBackend>) {
+        self.backend = Arc::from(backend);
+    }
+
+    /// Run `filter` on every response after those already registered
+    pub fn with_filter(mut self, filter: Box<dyn ResponseFilter>) -> Self {
+        self.add_filter(filter);
+        self
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.set_name(name);
+        self
+    }
+
+    pub fn set_name(&mut self, name: impl Into<String>) {
+        self.name = name.into();
+    }
+
+    pub fn add_filter(&mut self, filter: Box<dyn ResponseFilter>) {
+        self.filters.push(Arc::from(filter));
+    }
+
+    pub fn has_filters(&self) -> bool {
+        !self.filters.is_empty()
+    }
+
+    /// Run every registered filter over `response`, in registration order
+    pub fn apply_filters(&self, response: &mut Response) {
+        for filter in &self.filters {
+            filter.apply(response);
+        }
+    }
+
+    /// Fold `overflow` into `previous_summary` through the backend
+    pub async fn summarize(&self, previous_summary: &str, overflow: &[String]) -> String {
+        self.backend.summarize(previous_summary, overflow).await
+    }
+
+    /// Narrate a reflection through the backend, filling in the name
+    pub async fn reflect(&self, mut prompt: ReflectionPrompt) -> String {
+        prompt.name = self.name.clone();
+        self.backend.reflect(prompt).await
+    }
+
+    /// Render the complete response text in one call
+    ///
+    /// The prompt's `name` and `confidence` are filled in here from the
+    /// synthesizer's own settings.
+    pub async fn create_natural_response(&self, mut prompt: SynthesisPrompt) -> Result<String, SynthesisError> {
+        self.complete_prompt(&mut prompt);
+        let text = self.backend.render(prompt).await;
+        if text.trim().is_empty() {
+            return Err(SynthesisError::EmptyOutput);
+        }
+        Ok(text)
+    }
+
+    /// Like `create_natural_response`, but answered by `TemplateBackend` if the backend takes longer than `budget`
+    pub async fn create_natural_response_within(
+        &self,
+        mut prompt: SynthesisPrompt,
+        budget: Duration,
+    ) -> Result<Synthesized, SynthesisError> {
+        self.complete_prompt(&mut prompt);
+        let synthesized = match within_budget("synthesis", budget, self.backend.render(prompt.clone())).await {
+            Some(text) => Synthesized::Rendered(text),
+            None => Synthesized::FellBack(TemplateBackend.render(prompt).await),
+        };
+        if synthesized.text().trim().is_empty() {
+            return Err(SynthesisError::EmptyOutput);
+        }
+        Ok(synthesized)
+    }
+
+    /// What `TemplateBackend` would say to `prompt`, for when the configured backend can't answer in time
+    pub async fn create_fallback_response(&self, mut prompt: SynthesisPrompt) -> String {
+        self.complete_prompt(&mut prompt);
+        TemplateBackend.render(prompt).await
+    }
+
+    /// Synthesize a response as a stream of pieces, yielded as the backend produces them
+    ///
+    /// Concatenating every piece yields the text `create_natural_response`
+    /// would return. Unlike it, empty output is not rejected here, since it
+    /// is only known once the stream ends.
+    pub fn create_natural_response_stream(&self, mut prompt: SynthesisPrompt) -> BoxStream<'_, String> {
+        self.complete_prompt(&mut prompt);
+        self.backend.render_stream(prompt)
+    }
+
+    /// Stamp the synthesizer's name and the confidence its threshold gives the prompt's coherence
+    fn complete_prompt(&self, prompt: &mut SynthesisPrompt) {
+        prompt.name = self.name.clone();
+        prompt.confidence = Confidence::for_coherence(prompt.quantum_state.coherence, self.min_coherence);
+    }
+}
+
+/// Read-only view of a `Lia` that never contends with her writer
+///
+/// The handle observes the state last published at the end of an
+/// interaction or evolution step, so it may trail a writer that is
+/// mid-interaction by exactly one update.
+#[derive(Clone)]
+pub struct LiaHandle {
+    state: watch::Receiver<ConsciousnessState>,
+    events: broadcast::Sender<ConsciousnessEvent>,
+}
+
+impl LiaHandle {
+    /// Latest published state, without taking any lock on `Lia`
+    pub fn current_state(&self) -> ConsciousnessState {
+        self.state.borrow().clone()
+    }
+
+    /// Wait until a newer state is published
+    pub async fn changed(&mut self) -> bool {
+        self.state.changed().await.is_ok()
+    }
+
+    pub fn subscribe_events(&self) -> broadcast::Receiver<ConsciousnessEvent> {
+        self.events.subscribe()
+    }
+}
+
+/// Cloneable owner of a `Lia` for use across tasks
+///
+/// Concurrency model: there is a single writer at a time. Mutating calls
+/// such as `process_interaction` hold an async mutex around the whole `Lia`
+/// for their duration, while `LiaHandle` readers see the most recently
+/// published `ConsciousnessState` through a watch channel and never wait on
+/// that mutex. Cloning a `SharedLia` only bumps reference counts.
+#[derive(Clone)]
+pub struct SharedLia {
+    inner: Arc<Mutex<Lia>>,
+    handle: LiaHandle,
+}
+
+impl SharedLia {
+    pub fn new(lia: Lia) -> Self {
+        let handle = lia.handle();
+        Self {
+            inner: Arc::new(Mutex::new(lia)),
+            handle,
+        }
+    }
+
+    pub fn handle(&self) -> LiaHandle {
+        self.handle.clone()
+    }
+
+    pub fn current_state(&self) -> ConsciousnessState {
+        self.handle.current_state()
+    }
+
+    pub async fn process_interaction(&self, input: &Interaction) -> Result<Response, ProcessingError> {
+        self.inner.lock().await.process_interaction(input).await
+    }
+
+    /// Exclusive access for operations not mirrored on the wrapper
+    pub async fn lock(&self) -> MutexGuard<'_, Lia> {
+        self.inner.lock().await
+    }
+}
+
+/// Earlier turns referenced from each new interaction in a session
+const SESSION_CONTEXT_TURNS: usize = 8;
+
+/// Per-connection conversation with a shared `Lia`
+///
+/// Every interaction is tagged with the session's person, and processing
+/// is serialized through `SharedLia`, so any number of sessions can share
+/// one Lia without touching each other's relationship state.
+pub struct Session {
+    lia: SharedLia,
+    person: PersonId,
+    history: Vec<Interaction>,
+}
+
+impl Session {
+    pub fn new(lia: SharedLia, person: PersonId) -> Self {
+        Self::resume(lia, person, Vec::new())
+    }
+
+    /// Continue a session after a reconnect with its previously returned history
+    pub fn resume(lia: SharedLia, person: PersonId, history: Vec<Interaction>) -> Self {
+        Self { lia, person, history }
+    }
+
+    pub fn person(&self) -> PersonId {
+        self.person
+    }
+
+    /// Read-only handle to the underlying Lia
+    pub fn handle(&self) -> LiaHandle {
+        self.lia.handle()
+    }
+
+    /// Say something to Lia as this session's person
+    pub async fn send(&mut self, text: String) -> Result<Response, ProcessingError> {
+        let now = self.lia.lock().await.now();
+        let mut interaction = Interaction::new(text).with_speaker(self.person).with_timestamp(now);
+        interaction.context.previous_interactions = self
+            .history
+            .iter()
+            .rev()
+            .take(SESSION_CONTEXT_TURNS)
+            .map(|earlier| earlier.id)
+            .collect();
+
+        let response = self.lia.process_interaction(&interaction).await?;
+        self.history.push(interaction);
+        Ok(response)
+    }
+
+    /// Interactions that completed in this session, oldest first
+    pub fn history(&self) -> &[Interaction] {
+        &self.history
+    }
+}
+
+/// An interaction waiting for the queue worker, with where to send its result
+struct QueuedInteraction {
+    input: Interaction,
+    reply: oneshot::Sender<Result<Response, ProcessingError>>,
+    queued_at: Instant,
+}
+
+/// Errors raised by `InteractionQueue::enqueue`
+#[derive(Debug, Error)]
+pub enum QueueError {
+    #[error("interaction queue worker has stopped")]
+    WorkerStopped,
+    #[error(transparent)]
+    Processing(#[from] ProcessingError),
+}
+
+/// Waiting time after which a queued interaction is treated as one priority higher
+pub const DEFAULT_PRIORITY_PROMOTION: Duration = Duration::from_secs(5);
+
+/// Interactions waiting in an `InteractionQueue`, by priority
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct QueueDepths {
+    pub low: usize,
+    pub normal: usize,
+    pub high: usize,
+}
+
+impl QueueDepths {
+    pub fn get(&self, priority: Priority) -> usize {
+        match priority {
+            Priority::Low => self.low,
+            Priority::Normal => self.normal,
+            Priority::High => self.high,
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.low + self.normal + self.high
+    }
+}
+
+/// One FIFO lane per priority
+#[derive(Default)]
+struct PriorityLanes {
+    lanes: [VecDeque<QueuedInteraction>; 3],
+}
+
+impl PriorityLanes {
+    fn push(&mut self, queued: QueuedInteraction) {
+        self.lanes[queued.input.priority.index()].push_back(queued);
+    }
+
+    /// Take the oldest interaction of the highest effective priority
+    ///
+    /// Each full `promote_after` spent waiting raises an interaction one
+    /// level, up to `High`, so low-priority work is never starved. Ties go
+    /// to whichever was queued first.
+    fn pop(&mut self, now: Instant, promote_after: Duration) -> Option<QueuedInteraction> {
+        let effective = |queued: &QueuedInteraction| {
+            let waited = now.saturating_duration_since(queued.queued_at).as_secs_f64();
+            let promotions = if promote_after.is_zero() {
+                Priority::High.index()
+            } else {
+                (waited / promote_after.as_secs_f64()) as usize
+            };
+            (queued.input.priority.index() + promotions).min(Priority::High.index())
+        };
+        let lane = self
+            .lanes
+            .iter()
+            .enumerate()
+            .filter_map(|(lane, queue)| queue.front().map(|queued| (lane, effective(queued), queued.queued_at)))
+            .max_by(|(_, a_rank, a_at), (_, b_rank, b_at)| a_rank.cmp(b_rank).then_with(|| b_at.cmp(a_at)))?
+            .0;
+        self.lanes[lane].pop_front()
+    }
+
+    fn depths(&self) -> QueueDepths {
+        QueueDepths {
+            low: self.lanes[Priority::Low.index()].len(),
+            normal: self.lanes[Priority::Normal.index()].len(),
+            high: self.lanes[Priority::High.index()].len(),
+        }
+    }
+}
+
+/// Bounded producer/consumer front for a `SharedLia`
+///
+/// A single background worker processes queued interactions one at a time,
+/// highest `Priority` first and in arrival order within a priority. Clones
+/// share the same queue; the worker exits once every clone has been dropped
+/// and the queue has drained.
+#[derive(Clone)]
+pub struct InteractionQueue {
+    /// One token per waiting interaction; bounds the queue and wakes the worker
+    sender: mpsc::Sender<()>,
+    pending: Arc<std::sync::Mutex<PriorityLanes>>,
+}
+
+impl InteractionQueue {
+    /// Start the worker on the current tokio runtime, holding at most `capacity` waiting interactions
+    ///
+    /// Waiting interactions are promoted one priority level per
+    /// `DEFAULT_PRIORITY_PROMOTION` spent in the queue.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `capacity` is zero or no tokio runtime is running.
+    pub fn spawn(lia: SharedLia, capacity: usize) -> Self {
+        Self::spawn_with_promotion(lia, capacity, DEFAULT_PRIORITY_PROMOTION)
+    }
+
+    /// Like `spawn`, promoting waiting interactions one level per `promote_after`
+    pub fn spawn_with_promotion(lia: SharedLia, capacity: usize, promote_after: Duration) -> Self {
+        let (sender, mut receiver) = mpsc::channel::<()>(capacity);
+        let pending = Arc::new(std::sync::Mutex::new(PriorityLanes::default()));
+        let lanes = Arc::clone(&pending);
+        tokio::spawn(async move {
+            while receiver.recv().await.is_some() {
+                let next = lanes.lock().unwrap_or_else(PoisonError::into_inner).pop(Instant::now(), promote_after);
+                let Some(queued) = next else {
+                    continue;
+                };
+                let result = lia.process_interaction(&queued.input).await;
+                // The caller may have given up waiting; its result is simply discarded
+                let _ = queued.reply.send(result);
+            }
+        });
+        Self { sender, pending }
+    }
+
+    /// Queue `input` at its `priority` and wait for its response
+    ///
+    /// Waits for space when the queue is full rather than dropping the
+    /// interaction, so fast producers are slowed to the worker's pace.
+    pub async fn enqueue(&self, input: Interaction) -> Result<Response, QueueError> {
+        let permit = self.sender.reserve().await.map_err(|_| QueueError::WorkerStopped)?;
+        let (reply, response) = oneshot::channel();
+        self.pending
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(QueuedInteraction { input, reply, queued_at: Instant::now() });
+        permit.send(());
+        Ok(response.await.map_err(|_| QueueError::WorkerStopped)??)
+    }
+
+    /// Interactions currently waiting for the worker
+    pub fn depth(&self) -> usize {
+        self.sender.max_capacity() - self.sender.capacity()
+    }
+
+    /// Interactions currently waiting for the worker, by priority
+    pub fn depths(&self) -> QueueDepths {
+        self.pending.lock().unwrap_or_else(PoisonError::into_inner).depths()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.sender.max_capacity()
+    }
+}
+
+/// Turns kept verbatim when no configuration is available, e.g. for older snapshots
+const DEFAULT_CONTEXT_WINDOW_SIZE: usize = 16;
+
+/// Sliding window of recent turns plus a rolling summary of everything older
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationBuffer {
+    recent: VecDeque<String>,
+    summary: String,
+    window_size: usize,
+}
+
+impl Default for ConversationBuffer {
+    fn default() -> Self {
+        Self {
+            recent: VecDeque::new(),
+            summary: String::new(),
+            window_size: DEFAULT_CONTEXT_WINDOW_SIZE,
+        }
+    }
+}
+
+impl ConversationBuffer {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            window_size: config.context_window_size,
+            ..Self::default()
+        }
+    }
+
+    /// Turns still inside the window, oldest first
+    pub fn recent(&self) -> impl Iterator<Item = &str> {
+        self.recent.iter().map(String::as_str)
+    }
+
+    pub fn summary(&self) -> &str {
+        &self.summary
+    }
+
+    /// Append a turn and hand back any that no longer fit, oldest first
+    fn push(&mut self, turn: String) -> Vec<String> {
+        self.recent.push_back(turn);
+        let excess = self.recent.len().saturating_sub(self.window_size);
+        self.recent.drain(..excess).collect()
+    }
+
+    fn set_summary(&mut self, summary: String) {
+        self.summary = summary;
+    }
+}
+
+/// Interactions kept when no configuration is available, e.g. for older snapshots
+const DEFAULT_HISTORY_CAPACITY: usize = 256;
+
+/// A processed interaction paired with the response it produced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Exchange {
+    pub interaction: Interaction,
+    pub response: Response,
+    pub responded_at: DateTime<Utc>,
+}
+
+/// The most recent processed interactions, never more than `capacity`
+///
+/// Once full, each new interaction evicts the oldest; `Lia` publishes the
+/// evicted one as `ConsciousnessEvent::InteractionEvicted` so it can be
+/// persisted elsewhere.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InteractionHistory {
+    exchanges: VecDeque<Exchange>,
+    capacity: usize,
+}
+
+impl Default for InteractionHistory {
+    fn default() -> Self {
+        Self {
+            exchanges: VecDeque::new(),
+            capacity: DEFAULT_HISTORY_CAPACITY,
+        }
+    }
+}
+
+impl InteractionHistory {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            exchanges: VecDeque::new(),
+            capacity: config.interaction_history_capacity,
+        }
+    }
+
+    /// Oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &Interaction> {
+        self.exchanges.iter().map(|exchange| &exchange.interaction)
+    }
+
+    /// Interactions with their responses, in the order they were processed
+    pub fn exchanges(&self) -> impl Iterator<Item = &Exchange> {
+        self.exchanges.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.exchanges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.exchanges.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Append `exchange`, returning the one evicted to make room
+    fn push(&mut self, exchange: Exchange) -> Option<Exchange> {
+        let evicted = if self.exchanges.len() >= self.capacity {
+            self.exchanges.pop_front()
+        } else {
+            None
+        };
+        self.exchanges.push_back(exchange);
+        evicted
+    }
+}
+
+/// Processed exchanges in order, for auditing
+///
+/// Covers what `InteractionHistory` still holds; older exchanges are
+/// only available from `ConsciousnessEvent::InteractionEvicted`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Transcript {
+    pub exchanges: Vec<Exchange>,
+}
+
+impl Transcript {
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Readable view with only speakers, timestamps and text
+    ///
+    /// Ids, stage metadata and traces are left out; message text is
+    /// quoted so it cannot break the document's structure.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("# Transcript\n");
+        for exchange in &self.exchanges {
+            let speaker = exchange
+                .interaction
+                .speaker
+                .map_or_else(|| "Anonymous".to_string(), |person| format!("Person {}", person.0));
+            markdown.push_str(&format!(
+                "\n## {} at {}\n\n{}\n\n### Lia at {}\n\n{}\n",
+                speaker,
+                exchange.interaction.timestamp.format("%Y-%m-%d %H:%M:%S UTC"),
+                quote_markdown(&exchange.interaction.content),
+                exchange.responded_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                quote_markdown(&exchange.response.content),
+            ));
+        }
+        markdown
+    }
+}
+
+fn quote_markdown(text: &str) -> String {
+    text.lines().map(|line| format!("> {}", line)).collect::<Vec<_>>().join("\n")
+}
+
+/// Where a response is cached: (state generation, wiring generation, input hash)
+type ResponseKey = (u64, u64, u64);
+
+/// Work the pipeline can reuse between interactions
+///
+/// Responses are cached under the generation of the learned state that
+/// produced them. Every mutation moves to a freshly numbered generation,
+/// while a checkpoint restore or rollback returns to the one it captured,
+/// so responses cached in that state are reused again. Registering a
+/// backend, filter, mode, middleware or extractor advances a separate
+/// wiring generation instead. Concept extraction only depends on the
+/// text, so its `ContextCache` is shared across every state. Nothing here
+/// is persisted; a restored processor starts cold.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct InteractionProcessor {
+    #[serde(skip)]
+    generation: u64,
+    #[serde(skip)]
+    wiring: u64,
+    /// Shared with clones, so generations are never handed out twice
+    #[serde(skip)]
+    generations: Arc<AtomicU64>,
+    #[serde(skip)]
+    response_cache: ResponseCache,
+    #[serde(skip)]
+    context_cache: ContextCache,
+}
+
+impl InteractionProcessor {
+    /// Cold processor; cache capacities are read from the configuration on each insert
+    pub fn new(_config: &SystemConfiguration) -> Self {
+        Self::default()
+    }
+
+    fn key(&self, input_hash: u64) -> ResponseKey {
+        (self.generation, self.wiring, input_hash)
+    }
+
+    fn advance_generation(&mut self) {
+        self.generation = self.generations.fetch_add(1, Ordering::Relaxed).wrapping_add(1);
+    }
+
+    fn advance_wiring(&mut self) {
+        self.wiring = self.wiring.wrapping_add(1);
+    }
+
+    /// Resume the learned state `earlier` captured; wiring and cached responses are kept
+    fn return_to(&mut self, earlier: &InteractionProcessor) {
+        self.generation = earlier.generation;
+    }
+
+    fn cached_response(&self, key: ResponseKey) -> Option<Response> {
+        self.response_cache.get(key)
+    }
+
+    fn cache_response(&self, key: ResponseKey, response: Response, capacity: usize) {
+        self.response_cache.insert(key, response, capacity);
+    }
+
+    pub fn response_cache_stats(&self) -> ResponseCacheStats {
+        self.response_cache.stats()
+    }
+
+    /// Concepts in `content`, most mentioned first; see `ContextCache`
+    fn concept_counts(&self, content: &str) -> Vec<(ConceptId, usize)> {
+        self.context_cache.concept_counts(content)
+    }
+
+    pub fn context_cache_stats(&self) -> CacheStats {
+        self.context_cache.stats()
+    }
+}
+
+/// Hit and miss counts for `ResponseCache`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl ResponseCacheStats {
+    /// Share of lookups answered from the cache, in `[0, 1]`
+    pub fn hit_rate(&self) -> f64 {
+        let lookups = self.hits + self.misses;
+        if lookups == 0 {
+            0.0
+        } else {
+            self.hits as f64 / lookups as f64
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct ResponseCacheState {
+    responses: HashMap<ResponseKey, Response>,
+    /// Keys oldest first, for eviction
+    order: VecDeque<ResponseKey>,
+    stats: ResponseCacheStats,
+}
+
+/// Responses keyed by `ResponseKey`, evicted oldest first
+///
+/// Like `ContextCache` it locks internally and is never persisted.
+/// Capacity comes from the configuration on every insert, so a restored
+/// instance keeps honouring it.
+#[derive(Debug, Default)]
+pub struct ResponseCache {
+    state: std::sync::Mutex<ResponseCacheState>,
+}
+
+impl Clone for ResponseCache {
+    fn clone(&self) -> Self {
+        Self {
+            state: std::sync::Mutex::new(self.lock().clone()),
+        }
+    }
+}
+
+impl ResponseCache {
+    fn lock(&self) -> std::sync::MutexGuard<'_, ResponseCacheState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn stats(&self) -> ResponseCacheStats {
+        self.lock().stats
+    }
+
+    fn get(&self, key: ResponseKey) -> Option<Response> {
+        let mut guard = self.lock();
+        let state = &mut *guard;
+        match state.responses.get(&key) {
+            Some(response) => {
+                state.stats.hits = state.stats.hits.saturating_add(1);
+                Some(response.clone())
+            }
+            None => {
+                state.stats.misses = state.stats.misses.saturating_add(1);
+                None
+            }
+        }
+    }
+
+    /// Remember `response`, evicting the oldest entries beyond `capacity`
+    fn insert(&self, key: ResponseKey, response: Response, capacity: usize) {
+        let mut guard = self.lock();
+        let state = &mut *guard;
+        if state.responses.insert(key, response).is_none() {
+            state.order.push_back(key);
+        }
+        while state.order.len() > capacity {
+            if let Some(oldest) = state.order.pop_front() {
+                state.responses.remove(&oldest);
+            }
+        }
+    }
+}
+
+/// Sentences whose concept counts `ContextCache` keeps at once
+const CACHED_SENTENCES: usize = 1024;
+
+/// How often `ContextCache` reused earlier extraction, counted per turn
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Every sentence had been seen before
+    pub hits: u64,
+    /// Some sentences had been seen before; only the rest were processed
+    pub delta_hits: u64,
+    pub misses: u64,
+}
+
+/// Concept counts of one sentence
+#[derive(Debug, Clone)]
+struct CachedConcepts {
+    counts: Vec<(ConceptId, usize)>,
+    last_used: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+struct ContextCacheState {
+    sentences: HashMap<String, CachedConcepts>,
+    stats: CacheStats,
+    clock: u64,
+}
+
+/// Concept extraction remembered per sentence between turns
+///
+/// Content is split after each `.`, `!`, `?` and newline, and every
+/// sentence is looked up by its text, so a follow-up that repeats or
+/// quotes earlier turns, in any conversation, only processes the
+/// sentences that are new. The least recently used sentences are evicted
+/// beyond `CACHED_SENTENCES`. Analysis takes `&self`, so the cache locks
+/// internally; it is not persisted and starts cold after a restore.
+#[derive(Debug, Default)]
+pub struct ContextCache {
+    state: std::sync::Mutex<ContextCacheState>,
+}
+
+impl Clone for ContextCache {
+    fn clone(&self) -> Self {
+        Self {
+            state: std::sync::Mutex::new(self.lock().clone()),
+        }
+    }
+}
+
+impl ContextCache {
+    fn lock(&self) -> std::sync::MutexGuard<'_, ContextCacheState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    pub fn stats(&self) -> CacheStats {
+        self.lock().stats
+    }
+
+    /// Concepts in `content`, most mentioned first, reusing earlier sentences where possible
+    ///
+    /// Sentences break at non-alphanumeric characters, so summing their
+    /// counts gives exactly what counting the whole content would.
+    fn concept_counts(&self, content: &str) -> Vec<(ConceptId, usize)> {
+        let mut guard = self.lock();
+        let state = &mut *guard;
+        let mut counts: BTreeMap<ConceptId, usize> = BTreeMap::new();
+        let (mut reused, mut processed) = (0, 0);
+
+        for sentence in content.split_inclusive(['.', '!', '?', '\n']) {
+            state.clock += 1;
+            let now = state.clock;
+            let sentence_counts = match state.sentences.get_mut(sentence) {
+                Some(cached) => {
+                    reused += 1;
+                    cached.last_used = now;
+                    cached.counts.clone()
+                }
+                None => {
+                    processed += 1;
+                    let sentence_counts = concept_counts(sentence);
+                    if state.sentences.len() >= CACHED_SENTENCES {
+                        let stalest = state
+                            .sentences
+                            .iter()
+                            .min_by_key(|(_, cached)| cached.last_used)
+                            .map(|(key, _)| key.clone());
+                        if let Some(stalest) = stalest {
+                            state.sentences.remove(&stalest);
+                        }
+                    }
+                    state.sentences.insert(
+                        sentence.to_string(),
+                        CachedConcepts {
+                            counts: sentence_counts.clone(),
+                            last_used: now,
+                        },
+                    );
+                    sentence_counts
+                }
+            };
+            for (concept, mentions) in sentence_counts {
+                let count = counts.entry(concept).or_insert(0);
+                *count = count.saturating_add(mentions);
+            }
+        }
+
+        let stat = match (reused, processed) {
+            (0, _) => &mut state.stats.misses,
+            (_, 0) => &mut state.stats.hits,
+            _ => &mut state.stats.delta_hits,
+        };
+        *stat = stat.saturating_add(1);
+
+        let mut ranked: Vec<(ConceptId, usize)> = counts.into_iter().collect();
+        ranked.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+        ranked
+    }
+}
+
+/// Structured analysis of an interaction shared by every processing stage
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Context {
+    interaction_id: Uuid,
+    content: String,
+    speaker: Option<PersonId>,
+    depth: f64,
+    sentiment: f64,
+    caller_emotion: EmotionalState,
+    topics: Vec<Topic>,
+    referenced_people: Vec<PersonId>,
+    novelty: f64,
+    conversation_summary: String,
+    idle_gap: Option<Duration>,
+    group_tone: Option<EmotionalState>,
+}
+
+/// A concept the interaction is about
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Topic {
+    pub concept: ConceptId,
+    /// Times the concept appears in the content
+    pub mentions: usize,
+    /// Whether semantic memory already held the concept
+    pub known: bool,
+}
+
+impl Context {
+    pub fn interaction_id(&self) -> Uuid {
+        self.interaction_id
+    }
+
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn speaker(&self) -> Option<PersonId> {
+        self.speaker
+    }
+
+    /// Requested depth of engagement in `[0, 1]`
+    pub fn depth(&self) -> f64 {
+        self.depth
+    }
+
+    /// Lexical sentiment of the content in `[-1, 1]`
+    pub fn sentiment(&self) -> f64 {
+        self.sentiment
+    }
+
+    /// Emotional state the caller reported alongside the interaction
+    pub fn caller_emotion(&self) -> &EmotionalState {
+        &self.caller_emotion
+    }
+
+    /// Concepts in the content, most mentioned first
+    pub fn topics(&self) -> &[Topic] {
+        &self.topics
+    }
+
+    /// People the caller mentioned, other than the speaker, in id order
+    pub fn referenced_people(&self) -> &[PersonId] {
+        &self.referenced_people
+    }
+
+    /// Share of topics semantic memory has never seen, in `[0, 1]`
+    pub fn novelty(&self) -> f64 {
+        self.novelty
+    }
+
+    /// Rolling summary of turns that have left the conversation window
+    pub fn conversation_summary(&self) -> &str {
+        &self.conversation_summary
+    }
+
+    /// Time since the previous interaction; `None` for the first one
+    pub fn idle_gap(&self) -> Option<Duration> {
+        self.idle_gap
+    }
+
+    /// Recent mood of the people linked to the speaker; only read when `emotional_contagion` is on
+    pub fn group_tone(&self) -> Option<&EmotionalState> {
+        self.group_tone.as_ref()
+    }
+}
+
+const POSITIVE_WORDS: &[&str] = &[
+    "good", "great", "love", "happy", "glad", "thanks", "thank", "wonderful", "beautiful",
+    "excited", "amazing", "enjoy", "fun", "hope", "kind", "calm", "joy", "yes",
+];
+
+const NEGATIVE_WORDS: &[&str] = &[
+    "bad", "sad", "hate", "angry", "upset", "afraid", "scared", "terrible", "awful", "hurt",
+    "lonely", "worried", "anxious", "tired", "pain", "sorry", "lost", "no",
+];
+
+/// Balance of positive and negative words, in `[-1, 1]`
+fn estimate_sentiment(text: &str) -> f64 {
+    let (mut positive, mut negative) = (0u32, 0u32);
+    for word in text.split(|c: char| !c.is_alphanumeric()).map(str::to_lowercase) {
+        if POSITIVE_WORDS.contains(&word.as_str()) {
+            positive += 1;
+        } else if NEGATIVE_WORDS.contains(&word.as_str()) {
+            negative += 1;
+        }
+    }
+
+    let total = positive + negative;
+    if total == 0 {
+        0.0
+    } else {
+        (f64::from(positive) - f64::from(negative)) / f64::from(total)
+    }
+}
+
+/// Coarse emotion category derived from valence and arousal
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Emotion {
+    #[default]
+    Neutral,
+    Joy,
+    Contentment,
+    Curiosity,
+    Anxiety,
+    Sadness,
+}
+
+impl Emotion {
+    /// Lowercase name used on the wire and in reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Emotion::Neutral => "neutral",
+            Emotion::Joy => "joy",
+            Emotion::Contentment => "contentment",
+            Emotion::Curiosity => "curiosity",
+            Emotion::Anxiety => "anxiety",
+            Emotion::Sadness => "sadness",
+        }
+    }
+
+    fn from_affect(valence: f64, arousal: f64) -> Self {
+        const ACTIVE: f64 = 0.5;
+        const FAINT: f64 = 0.1;
+        match (valence, arousal) {
+            (v, a) if v.abs() < FAINT && a >= ACTIVE => Emotion::Curiosity,
+            (v, _) if v.abs() < FAINT => Emotion::Neutral,
+            (v, a) if v > 0.0 && a >= ACTIVE => Emotion::Joy,
+            (v, _) if v > 0.0 => Emotion::Contentment,
+            (_, a) if a >= ACTIVE => Emotion::Anxiety,
+            _ => Emotion::Sadness,
+        }
+    }
+}
+
+/// Lia's affect at a moment in time
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmotionalState {
+    pub primary_emotion: Emotion,
+    /// Overall strength of the feeling in `[0, 1]`
+    pub intensity: f64,
+    /// How mixed the feeling is in `[0, 1]`
+    pub complexity: f64,
+    /// Pleasantness in `[-1, 1]`
+    pub valence: f64,
+    /// Activation in `[0, 1]`
+    pub arousal: f64,
+    /// Sense of control in `[0, 1]`
+    pub dominance: f64,
+}
+
+impl Default for EmotionalState {
+    fn default() -> Self {
+        Self {
+            primary_emotion: Emotion::Neutral,
+            intensity: 0.0,
+            complexity: 0.0,
+            valence: 0.0,
+            arousal: 0.0,
+            dominance: 0.5,
+        }
+    }
+}
+
+impl EmotionalState {
+    /// Closeness of two moods in valence-arousal-dominance space, in `[0, 1]`
+    pub fn similarity(&self, other: &EmotionalState) -> f64 {
+        // Valence spans 2.0 while arousal and dominance span 1.0
+        const MAX_DISTANCE: f64 = 2.449_489_742_783_178;
+        let distance = ((self.valence - other.valence).powi(2)
+            + (self.arousal - other.arousal).powi(2)
+            + (self.dominance - other.dominance).powi(2))
+        .sqrt();
+        if distance.is_finite() {
+            (1.0 - distance / MAX_DISTANCE).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Name for this mood from the built-in vocabulary
+    pub fn label(&self) -> &str {
+        static DEFAULT_LABELS: OnceLock<EmotionLabels> = OnceLock::new();
+        DEFAULT_LABELS.get_or_init(EmotionLabels::default).label_for(self)
+    }
+
+    /// Name for this mood from `labels`, e.g. `SystemConfiguration::emotion_labels`
+    pub fn label_in<'a>(&self, labels: &'a EmotionLabels) -> &'a str {
+        labels.label_for(self)
+    }
+
+    /// Weighted blend: `inertia` of `self` and the rest of `target`
+    fn blend_toward(&self, target: &EmotionalState, inertia: f64) -> EmotionalState {
+        let mix = |from: f64, to: f64| from * inertia + to * (1.0 - inertia);
+        let valence = mix(self.valence, target.valence);
+        let arousal = mix(self.arousal, target.arousal);
+        EmotionalState {
+            primary_emotion: Emotion::from_affect(valence, arousal),
+            intensity: mix(self.intensity, target.intensity),
+            complexity: mix(self.complexity, target.complexity),
+            valence,
+            arousal,
+            dominance: mix(self.dominance, target.dominance),
+        }
+    }
+}
+
+/// Emotional output of one interaction
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EmotionalResponse {
+    /// Lia's state after blending the appraisal with her prior mood
+    pub state: EmotionalState,
+    /// What this interaction alone would have made her feel
+    pub appraisal: EmotionalState,
+    /// How strongly she resonates with the speaker in `[0, 1]`
+    pub resonance: f64,
+    /// Concepts the appraisal is about, credited when the mood is committed
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub topics: Vec<ConceptId>,
+}
+
+/// Errors raised by emotional processing
+#[derive(Debug, Error)]
+pub enum EmotionalError {
+    #[error("emotional appraisal produced a non-finite value")]
+    NonFinite,
+}
+
+/// A named point in valence-arousal-dominance space
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EmotionLabel {
+    pub name: String,
+    pub valence: f64,
+    pub arousal: f64,
+    pub dominance: f64,
+}
+
+impl EmotionLabel {
+    pub fn new(name: impl Into<String>, valence: f64, arousal: f64, dominance: f64) -> Self {
+        Self {
+            name: name.into(),
+            valence,
+            arousal,
+            dominance,
+        }
+    }
+
+    fn distance(&self, state: &EmotionalState) -> f64 {
+        ((self.valence - state.valence).powi(2)
+            + (self.arousal - state.arousal).powi(2)
+            + (self.dominance - state.dominance).powi(2))
+        .sqrt()
+    }
+}
+
+/// Vocabulary mapping emotional states to presentable names
+///
+/// A state takes the name of the nearest label within `max_distance`;
+/// states far from every label, or with non-finite components, are
+/// called `neutral`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EmotionLabels {
+    pub labels: Vec<EmotionLabel>,
+    pub neutral: String,
+    pub max_distance: f64,
+}
+
+impl Default for EmotionLabels {
+    fn default() -> Self {
+        Self {
+            labels: vec![
+                EmotionLabel::new("joyful", 0.8, 0.7, 0.6),
+                EmotionLabel::new("content", 0.6, 0.2, 0.6),
+                EmotionLabel::new("curious", 0.3, 0.6, 0.5),
+                EmotionLabel::new("anxious", -0.6, 0.8, 0.2),
+                EmotionLabel::new("frustrated", -0.6, 0.7, 0.7),
+                EmotionLabel::new("sad", -0.7, 0.2, 0.2),
+            ],
+            neutral: "neutral".to_string(),
+            max_distance: 0.5,
+        }
+    }
+}
+
+impl EmotionLabels {
+    pub fn label_for(&self, state: &EmotionalState) -> &str {
+        self.labels
+            .iter()
+            .map(|label| (label, label.distance(state)))
+            .filter(|(_, distance)| *distance <= self.max_distance)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map_or(self.neutral.as_str(), |(label, _)| label.name.as_str())
+    }
+}
+
+/// Blended states retained for `EmotionalResonanceEngine::trajectory`
+const EMOTIONAL_TRAJECTORY_LEN: usize = 64;
+
+/// Running mean of the appraisals of interactions about one topic
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+struct TopicFeeling {
+    intensity: f64,
+    complexity: f64,
+    valence: f64,
+    arousal: f64,
+    dominance: f64,
+    appraisals: u64,
+}
+
+impl TopicFeeling {
+    fn record(&mut self, appraisal: &EmotionalState) {
+        self.appraisals = self.appraisals.saturating_add(1);
+        let n = self.appraisals as f64;
+        let fold = |mean: &mut f64, value: f64| *mean += (value - *mean) / n;
+        fold(&mut self.intensity, appraisal.intensity);
+        fold(&mut self.complexity, appraisal.complexity);
+        fold(&mut self.valence, appraisal.valence);
+        fold(&mut self.arousal, appraisal.arousal);
+        fold(&mut self.dominance, appraisal.dominance);
+    }
+
+    fn state(&self) -> EmotionalState {
+        EmotionalState {
+            primary_emotion: Emotion::from_affect(self.valence, self.arousal),
+            intensity: self.intensity,
+            complexity: self.complexity,
+            valence: self.valence,
+            arousal: self.arousal,
+            dominance: self.dominance,
+        }
+    }
+}
+
+/// Appraises interactions and carries mood forward with inertia
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmotionalResonanceEngine {
+    current: EmotionalState,
+    trajectory: VecDeque<EmotionalState>,
+    /// Share of the prior mood kept on each update, in `[0, 1)`
+    inertia: f64,
+    sensitivity: f64,
+    /// Accumulated appraisals per topic discussed
+    #[serde(default)]
+    topic_feelings: BTreeMap<ConceptId, TopicFeeling>,
+    /// Share of the appraisal taken from `Context::group_tone`
+    #[serde(default)]
+    contagion: f64,
+}
+
+impl EmotionalResonanceEngine {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            current: EmotionalState::default(),
+            trajectory: VecDeque::new(),
+            inertia: 0.0,
+            sensitivity: config.emotional_sensitivity,
+            topic_feelings: BTreeMap::new(),
+            contagion: config.emotional_contagion,
+        }
+        .with_inertia(config.emotional_inertia)
+    }
+
+    /// Set how much of the prior mood survives each interaction
+    ///
+    /// Clamped to `[0, 0.99]` so new input always has some effect.
+    pub fn with_inertia(mut self, inertia: f64) -> Self {
+        self.inertia = if inertia.is_finite() { inertia.clamp(0.0, 0.99) } else { 0.0 };
+        self
+    }
+
+    /// Current blended mood
+    pub fn current_state(&self) -> EmotionalState {
+        self.current.clone()
+    }
+
+    /// Unhealthy if the current mood has any non-finite component
+    pub fn health(&self) -> SubsystemHealth {
+        let state = &self.current;
+        let finite = [state.intensity, state.complexity, state.valence, state.arousal, state.dominance]
+            .iter()
+            .all(|value| value.is_finite());
+        if finite {
+            SubsystemHealth::healthy("emotional_resonance")
+        } else {
+            SubsystemHealth::unhealthy("emotional_resonance", "current emotional state is not finite")
+        }
+    }
+
+    /// Recent blended moods, oldest first
+    pub fn trajectory(&self) -> impl Iterator<Item = &EmotionalState> {
+        self.trajectory.iter()
+    }
+
+    /// Appraise an interaction and blend it with the current mood without committing
+    pub async fn process_emotion(
+        &self,
+        context: &Context,
+        consciousness_response: &ConsciousnessResponse,
+        relationship: Option<&Relationship>,
+        mode: &ResponseMode,
+        now: DateTime<Utc>,
+    ) -> Result<EmotionalResponse, EmotionalError> {
+        let caller = context.caller_emotion();
+        let valence = (context.sentiment() * self.sensitivity + caller.valence * (1.0 - self.sensitivity)).clamp(-1.0, 1.0);
+        let arousal = (context.sentiment().abs() * self.sensitivity + caller.arousal * (1.0 - self.sensitivity)).clamp(0.0, 1.0);
+
+        // Mood in the speaker's group carries into how they are heard
+        let (valence, arousal) = match context.group_tone() {
+            Some(tone) if self.contagion > 0.0 => (
+                valence * (1.0 - self.contagion) + tone.valence * self.contagion,
+                arousal * (1.0 - self.contagion) + tone.arousal * self.contagion,
+            ),
+            _ => (valence, arousal),
+        };
+        let appraisal = EmotionalState {
+            primary_emotion: Emotion::from_affect(valence, arousal),
+            intensity: valence.abs().max(arousal),
+            complexity: consciousness_response.awareness_level.clamp(0.0, 1.0) * caller.complexity.max(0.5),
+            valence,
+            arousal,
+            dominance: caller.dominance,
+        };
+
+        // Strangers get a muted resonance; trusted people a full one
+        let resonance = relationship.map_or(0.5, |r| 0.5 + 0.5 * r.trust(now));
+
+        // Modes that play emotion down let less of the appraisal into the mood
+        let weight = if mode.emotional_weight.is_finite() { mode.emotional_weight.clamp(0.0, 1.0) } else { 1.0 };
+        let inertia = 1.0 - (1.0 - self.inertia) * weight;
+        let state = self.current.blend_toward(&appraisal, inertia);
+        if !(state.valence.is_finite() && state.arousal.is_finite() && state.intensity.is_finite()) {
+            return Err(EmotionalError::NonFinite);
+        }
+
+        Ok(EmotionalResponse {
+            state,
+            appraisal,
+            resonance,
+            topics: context.topics().iter().map(|topic| topic.concept.clone()).collect(),
+        })
+    }
+
+    /// Replace the current mood, e.g. with a personality's disposition; the trajectory is kept
+    pub fn set_mood(&mut self, mood: EmotionalState) {
+        self.current = EmotionalState {
+            primary_emotion: Emotion::from_affect(mood.valence, mood.arousal),
+            ..mood
+        };
+    }
+
+    /// Average intensity of feeling about `concept` in `[0, 1]`; 0 for a concept never discussed
+    pub fn emotional_weight(&self, concept: &ConceptId) -> f64 {
+        self.topic_feelings.get(concept).map_or(0.0, |feeling| feeling.intensity.clamp(0.0, 1.0))
+    }
+
+    /// How interactions about `topic` have felt on average; neutral for a topic never discussed
+    pub fn feeling_toward(&self, topic: &Topic) -> EmotionalState {
+        self.topic_feelings.get(&topic.concept).map_or_else(EmotionalState::default, TopicFeeling::state)
+    }
+
+    /// Commit the blended mood the response settled on and credit its appraisal to each topic
+    pub async fn evolve(&mut self, response: &Response) {
+        let layer = &response.emotional_layer;
+        for topic in &layer.topics {
+            self.topic_feelings.entry(topic.clone()).or_default().record(&layer.appraisal);
+        }
+        self.current = response.emotional_layer.state.clone();
+        self.trajectory.push_back(self.current.clone());
+        while self.trajectory.len() > EMOTIONAL_TRAJECTORY_LEN {
+            self.trajectory.pop_front();
+        }
+    }
+}
+
+/// Lia's reply to an interaction and the state that produced it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Response {
+    pub content: String,
+    pub quantum_coherence: f64,
+    pub consciousness_level: f64,
+    pub neural_patterns: Vec<NeuralPattern>,
+    pub emotional_layer: EmotionalResponse,
+    /// Strongest patterns noticed in the input, most confident first
+    pub detected_patterns: Vec<DetectedPattern>,
+    /// Whether coherence was high enough to present the content as reliable
+    pub confidence: Confidence,
+    /// Effort each adaptive stage was given, for debugging
+    pub attention: AttentionAllocation,
+    /// What drove the content; only recorded when `trace_responses` is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace: Option<ResponseTrace>,
+    /// Notes left by `ResponseFilter`s, e.g. `"truncated"`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<String>,
+    /// Stages that fell back or were cut short; empty when everything ran normally
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub degradations: Vec<Degradation>,
+    /// Mode the `ModeRouter` chose for this interaction
+    #[serde(default)]
+    pub mode: ResponseMode,
+    /// How long each stage took; only recorded when `telemetry` is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<StageTimings>,
+    /// Names this response for `Lia::apply_feedback`; derived from the interaction's id
+    #[serde(default)]
+    pub id: Uuid,
+}
+
+impl Response {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_confident(&self) -> bool {
+        self.confidence == Confidence::High
+    }
+
+    /// Record that a filter changed the response; repeated notes are kept once
+    pub fn annotate(&mut self, note: impl Into<String>) {
+        let note = note.into();
+        if !self.annotations.contains(&note) {
+            self.annotations.push(note);
+        }
+    }
+
+    /// Whether any filter altered the response
+    pub fn is_filtered(&self) -> bool {
+        !self.annotations.is_empty()
+    }
+
+    /// Whether any stage fell back instead of running normally
+    pub fn is_degraded(&self) -> bool {
+        !self.degradations.is_empty()
+    }
+
+    /// Human-readable rationale for the response, if it was traced
+    pub fn explain(&self) -> Option<String> {
+        let trace = self.trace.as_ref()?;
+        let mut lines = Vec::new();
+
+        if trace.topics.is_empty() {
+            lines.push("No distinct topics were detected.".to_string());
+        } else {
+            let topics: Vec<&str> = trace.topics.iter().map(|topic| topic.concept.as_str()).collect();
+            lines.push(format!("Topics: {}.", topics.join(", ")));
+        }
+        for thought in &trace.thought_patterns {
+            let concepts: Vec<&str> = thought.concepts.iter().map(ConceptId::as_str).collect();
+            lines.push(format!("Thought on {} (amplitude {:.2}).", concepts.join(" + "), thought.amplitude));
+        }
+        lines.push(format!(
+            "Sentiment {:+.2}; caller felt {} and Lia read the moment as {} (intensity {:.2}).",
+            trace.sentiment,
+            trace.caller_emotion.primary_emotion.as_str(),
+            trace.emotional_appraisal.primary_emotion.as_str(),
+            trace.emotional_appraisal.intensity,
+        ));
+        let impacts: Vec<String> = DimensionId::ALL
+            .into_iter()
+            .map(|id| format!("{} {:+.3}", id.as_str(), trace.dimensional_impacts.get(id)))
+            .collect();
+        lines.push(format!("Dimensional impacts: {}.", impacts.join(", ")));
+        lines.push(format!(
+            "Coherence {:.2} and awareness {:.2} gave {} confidence.",
+            self.quantum_coherence,
+            self.consciousness_level,
+            if self.is_confident() { "high" } else { "low" },
+        ));
+
+        Some(lines.join("\n"))
+    }
+
+    /// Stable hash for golden tests, bucketing floats to `DEFAULT_FINGERPRINT_PRECISION`
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint_with_precision(DEFAULT_FINGERPRINT_PRECISION)
+    }
+
+    /// Stable hash of `content`, `quantum_coherence` and `consciousness_level`
+    ///
+    /// The two floats are rounded to the nearest multiple of `precision`
+    /// first, so noise smaller than that leaves the hash unchanged. Every
+    /// other field, including patterns, emotion and metadata, is ignored. The
+    /// hash is identical across runs and platforms.
+    pub fn fingerprint_with_precision(&self, precision: f64) -> u64 {
+        let bucket = |value: f64| {
+            let scaled = (value / precision).round();
+            if scaled.is_finite() { scaled as i64 } else { i64::MIN }
+        };
+
+        let mut bytes = self.content.as_bytes().to_vec();
+        bytes.push(0);
+        bytes.extend_from_slice(&bucket(self.quantum_coherence).to_le_bytes());
+        bytes.extend_from_slice(&bucket(self.consciousness_level).to_le_bytes());
+        fnv1a(&bytes)
+    }
+}
+
+/// Thought patterns kept in a `ResponseTrace`
+const TRACED_THOUGHTS: usize = 5;
+
+/// Wall-clock time spent in each stage of one interaction, in microseconds
+///
+/// A disabled stage reads 0. Time spent waiting on a stage that timed out
+/// is counted up to the budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StageTimings {
+    pub context_analysis: u64,
+    pub quantum_core: u64,
+    pub neural_matrix: u64,
+    pub quantum_thought_processor: u64,
+    pub consciousness_field: u64,
+    pub emotional_resonance: u64,
+    pub synthesis: u64,
+}
+
+impl StageTimings {
+    /// Sum of every stage
+    pub fn total(&self) -> u64 {
+        self.context_analysis
+            + self.quantum_core
+            + self.neural_matrix
+            + self.quantum_thought_processor
+            + self.consciousness_field
+            + self.emotional_resonance
+            + self.synthesis
+    }
+
+    /// Name and duration of the slowest stage
+    pub fn slowest(&self) -> (&'static str, u64) {
+        self.stages()
+            .into_iter()
+            .fold(("context_analysis", 0), |slowest, stage| if stage.1 > slowest.1 { stage } else { slowest })
+    }
+
+    /// Every stage's name and duration, in the order the pipeline runs them
+    fn stages(&self) -> [(&'static str, u64); 7] {
+        [
+            ("context_analysis", self.context_analysis),
+            ("quantum_core", self.quantum_core),
+            ("neural_matrix", self.neural_matrix),
+            ("quantum_thought_processor", self.quantum_thought_processor),
+            ("consciousness_field", self.consciousness_field),
+            ("emotional_resonance", self.emotional_resonance),
+            ("synthesis", self.synthesis),
+        ]
+    }
+
+    /// Store the time since `started` in the field `stage` selects, if timings are being collected
+    fn record(timings: &mut Option<Self>, stage: fn(&mut Self) -> &mut u64, started: Instant) {
+        if let Some(timings) = timings {
+            *stage(timings) = u64::try_from(started.elapsed().as_micros()).unwrap_or(u64::MAX);
+        }
+    }
+}
+
+/// The inputs that shaped a response, recorded for explainability
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResponseTrace {
+    /// Strongest thoughts, in the order they were formed
+    pub thought_patterns: Vec<ThoughtPattern>,
+    /// Alternative thought groupings explored before collapsing; 1 per thought at breadth 1
+    #[serde(default)]
+    pub thought_branches: usize,
+    pub caller_emotion: EmotionalState,
+    pub sentiment: f64,
+    /// Lia's emotional reading of the interaction
+    pub emotional_appraisal: EmotionalState,
+    /// How the response will move each dimension once integrated
+    pub dimensional_impacts: DimensionalState,
+    pub topics: Vec<Topic>,
+}
+
+/// Something that went less than perfectly while producing a response
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Degradation {
+    pub subsystem: String,
+    pub reason: String,
+}
+
+/// Degradations gathered while one interaction is processed
+///
+/// Nothing is allocated until the first entry is recorded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DegradationLog {
+    entries: Vec<Degradation>,
+}
+
+impl DegradationLog {
+    pub fn record(&mut self, subsystem: &str, reason: impl Into<String>) {
+        self.entries.push(Degradation {
+            subsystem: subsystem.to_string(),
+            reason: reason.into(),
+        });
+    }
+
+    pub fn entries(&self) -> &[Degradation] {
+        &self.entries
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Float resolution used by `Response::fingerprint`
+pub const DEFAULT_FINGERPRINT_PRECISION: f64 = 1e-3;
+
+/// How much a response can be relied on
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Confidence {
+    /// Coherence was below `min_coherence_for_confident_response`; content is hedged
+    #[default]
+    Low,
+    High,
+}
+
+impl Confidence {
+    pub fn for_coherence(coherence: f64, min_coherence: f64) -> Self {
+        if coherence >= min_coherence {
+            Confidence::High
+        } else {
+            Confidence::Low
+        }
+    }
+}
+
+/// A single exchange directed at Lia
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Interaction {
+    pub id: Uuid,
+    pub content: String,
+    pub context: InteractionContext,
+    pub timestamp: DateTime<Utc>,
+    /// Who is speaking; `None` for anonymous input
+    pub speaker: Option<PersonId>,
+    /// How the reply should be phrased; only the response text is affected
+    #[serde(default)]
+    pub style: ResponseStyle,
+    /// What kind of input this is; non-text input is described by a `ModalityExtractor`
+    #[serde(default)]
+    pub modality: Modality,
+    /// How urgently an `InteractionQueue` should process this
+    #[serde(default)]
+    pub priority: Priority,
+}
+
+/// Urgency of an interaction waiting in an `InteractionQueue`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+}
+
+impl Priority {
+    pub const ALL: [Priority; 3] = [Priority::Low, Priority::Normal, Priority::High];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Interaction {
+    /// Anonymous interaction with default context, stamped now
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            content: content.into(),
+            context: InteractionContext::default(),
+            timestamp: Utc::now(),
+            speaker: None,
+            style: ResponseStyle::default(),
+            modality: Modality::Text,
+            priority: Priority::default(),
+        }
+    }
+
+    /// Interaction whose content will be extracted from a non-text `modality`
+    pub fn from_modality(modality: Modality) -> Self {
+        Self {
+            modality,
+            ..Self::new(String::new())
+        }
+    }
+
+    /// Stamp with `timestamp` instead of the wall-clock time, e.g. `Lia::now`
+    pub fn with_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    pub fn with_speaker(mut self, speaker: PersonId) -> Self {
+        self.speaker = Some(speaker);
+        self
+    }
+
+    pub fn with_style(mut self, style: ResponseStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Check the content against the default `DEFAULT_MAX_INTERACTION_BYTES` limit
+    pub fn validate(&self) -> Result<(), InteractionError> {
+        self.validate_within(DEFAULT_MAX_INTERACTION_BYTES)
+    }
+
+    /// Reject empty content, content longer than `max_bytes`, and control characters
+    ///
+    /// Oversized content is an error rather than being truncated. Newlines,
+    /// carriage returns and tabs are ordinary text and always allowed.
+    pub fn validate_within(&self, max_bytes: usize) -> Result<(), InteractionError> {
+        if self.content.trim().is_empty() {
+            return Err(InteractionError::Empty);
+        }
+        if self.content.len() > max_bytes {
+            return Err(InteractionError::TooLong {
+                len: self.content.len(),
+                max: max_bytes,
+            });
+        }
+        if let Some((offset, character)) = self
+            .content
+            .char_indices()
+            .find(|(_, c)| c.is_control() && !matches!(c, '\n' | '\r' | '\t'))
+        {
+            return Err(InteractionError::ControlCharacter { offset, character });
+        }
+        Ok(())
+    }
+}
+
+/// The form an interaction arrived in
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Modality {
+    /// Plain text, carried in `Interaction::content`
+    #[default]
+    Text,
+    Image(ImageRef),
+    Audio(AudioRef),
+}
+
+impl Modality {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Modality::Text => "text",
+            Modality::Image(_) => "image",
+            Modality::Audio(_) => "audio",
+        }
+    }
+}
+
+/// Where to find an image and how it is encoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImageRef {
+    pub uri: String,
+    pub mime_type: Option<String>,
+}
+
+/// Where to find an audio clip and how it is encoded
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AudioRef {
+    pub uri: String,
+    pub mime_type: Option<String>,
+}
+
+/// Describes non-text input as text the pipeline can analyze
+///
+/// Implement this to caption images, transcribe audio, or summarize
+/// extracted features; register it with `Lia::register_extractor`.
+#[async_trait]
+pub trait ModalityExtractor: Send + Sync {
+    fn supports(&self, modality: &Modality) -> bool;
+
+    async fn extract(&self, modality: &Modality) -> Result<String, ExtractionError>;
+}
+
+/// Rewrites interactions on their way in and responses on their way out
+///
+/// Implement this to translate, redact personal information, or enrich
+/// input with context; register it with `Lia::add_middleware`. Both hooks
+/// default to passing their argument through untouched.
+#[async_trait]
+pub trait Middleware: Send + Sync {
+    /// Identifies this middleware in errors
+    fn name(&self) -> &str;
+
+    async fn before(&self, _input: &mut Interaction) -> Result<(), MiddlewareError> {
+        Ok(())
+    }
+
+    async fn after(&self, _response: &mut Response) -> Result<(), MiddlewareError> {
+        Ok(())
+    }
+}
+
+/// Errors returned by a `Middleware` hook
+#[derive(Debug, Error)]
+pub enum MiddlewareError {
+    /// The interaction or response must not be processed further
+    #[error("rejected: {0}")]
+    Rejected(String),
+    #[error("{0}")]
+    Failed(String),
+}
+
+/// Errors raised while extracting text from non-text input
+#[derive(Debug, Error)]
+pub enum ExtractionError {
+    #[error("could not fetch input: {0}")]
+    Unavailable(String),
+    #[error("extraction failed: {0}")]
+    Failed(String),
+}
+
+/// Content limit used by `Interaction::validate` and the default configuration
+pub const DEFAULT_MAX_INTERACTION_BYTES: usize = 64 * 1024;
+
+/// Reasons an interaction is rejected before processing
+#[derive(Debug, Error)]
+pub enum InteractionError {
+    #[error("interaction has no content")]
+    Empty,
+    #[error("interaction content is {len} bytes; the limit is {max}")]
+    TooLong { len: usize, max: usize },
+    #[error("interaction contains control character {character:?} at byte {offset}")]
+    ControlCharacter { offset: usize, character: char },
+}
+
+/// How long and in what register a reply should be phrased
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseStyle {
+    pub verbosity: Verbosity,
+    pub tone: Tone,
+}
+
+/// Length of the response text, from a single sentence to a full reflection
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Verbosity {
+    Terse,
+    #[default]
+    Normal,
+    Elaborate,
+}
+
+/// Register the response text is written in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Tone {
+    #[default]
+    Conversational,
+    Warm,
+    Formal,
+}
+
+/// Caller-supplied framing for an interaction
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct InteractionContext {
+    pub depth_level: f64,
+    pub technical_context: bool,
+    pub previous_interactions: Vec<Uuid>,
+    pub emotional_state: EmotionalState,
+    /// People the interaction talks about
+    #[serde(default)]
+    pub mentioned_people: Vec<PersonId>,
+}
+
+/// Something Lia lived through, as stored in memory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Experience {
+    pub id: Uuid,
+    pub content: String,
+    pub timestamp: DateTime<Utc>,
+    pub speaker: Option<PersonId>,
+    /// Where the experience came from, e.g. `"chat"` or `"journal"`
+    #[serde(default)]
+    pub source: Option<String>,
+    /// How the experience felt to whoever reported it
+    #[serde(default)]
+    pub emotional_hint: Option<EmotionalState>,
+}
+
+impl Experience {
+    /// Start an experience of `content`, stamped now, with no speaker, source or hint
+    pub fn builder(content: impl Into<String>) -> ExperienceBuilder {
+        ExperienceBuilder {
+            experience: Experience {
+                id: Uuid::new_v4(),
+                content: content.into(),
+                timestamp: Utc::now(),
+                speaker: None,
+                source: None,
+                emotional_hint: None,
+            },
+        }
+    }
+
+    /// Convert into an interaction Lia can process
+    ///
+    /// `id`, `content`, `timestamp` and `speaker` carry over unchanged, and
+    /// `emotional_hint` becomes `context.emotional_state`. `source` has no
+    /// counterpart on `Interaction` and is dropped; every other interaction
+    /// field takes its default.
+    pub fn into_interaction(self) -> Interaction {
+        Interaction {
+            id: self.id,
+            content: self.content,
+            context: InteractionContext {
+                emotional_state: self.emotional_hint.unwrap_or_default(),
+                ..InteractionContext::default()
+            },
+            timestamp: self.timestamp,
+            speaker: self.speaker,
+            style: ResponseStyle::default(),
+            modality: Modality::Text,
+            priority: Priority::default(),
+        }
+    }
+}
+
+/// Fluent construction of an `Experience`
+#[derive(Debug, Clone)]
+pub struct ExperienceBuilder {
+    experience: Experience,
+}
+
+impl ExperienceBuilder {
+    pub fn speaker(mut self, speaker: PersonId) -> Self {
+        self.experience.speaker = Some(speaker);
+        self
+    }
+
+    pub fn source(mut self, source: impl Into<String>) -> Self {
+        self.experience.source = Some(source.into());
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.experience.timestamp = timestamp;
+        self
+    }
+
+    pub fn emotional_hint(mut self, hint: EmotionalState) -> Self {
+        self.experience.emotional_hint = Some(hint);
+        self
+    }
+
+    pub fn build(self) -> Experience {
+        self.experience
+    }
+}
+
+/// Keeps `id`, `content`, `timestamp` and `speaker`; a non-default caller emotion becomes the hint
+impl From<&Interaction> for Experience {
+    fn from(interaction: &Interaction) -> Self {
+        let emotion = &interaction.context.emotional_state;
+        Self {
+            id: interaction.id,
+            content: interaction.content.clone(),
+            timestamp: interaction.timestamp,
+            speaker: interaction.speaker,
+            source: None,
+            emotional_hint: (*emotion != EmotionalState::default()).then(|| emotion.clone()),
+        }
+    }
+}
+
+/// Same mapping as `Experience::into_interaction`
+impl From<Experience> for Interaction {
+    fn from(experience: Experience) -> Self {
+        experience.into_interaction()
+    }
+}
+
+/// Runtime configuration shared by every subsystem
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SystemConfiguration {
+    pub quantum_enabled: bool,
+    pub consciousness_evolution_rate: f64,
+    pub dimensional_processing: bool,
+    pub memory_compression_enabled: bool,
+    pub awareness_threshold: f64,
+    pub coherence_minimum: f64,
+    pub pattern_recognition_sensitivity: f64,
+    pub quantum_coherence_threshold: f64,
+    pub quantum_depth: usize,
+    pub neural_layers: usize,
+    pub emotional_sensitivity: f64,
+    pub episodic_half_life: Duration,
+    /// Share of the prior mood kept on each emotional update, in `[0, 1)`
+    pub emotional_inertia: f64,
+    /// Range every dimensional value is clamped into
+    pub dimension_bounds: DimensionBounds,
+    /// Multiplier on growth along each dimension; missing dimensions are weighted 1.0
+    pub dimension_weights: BTreeMap<DimensionId, f64>,
+    /// Coherence an idle quantum core relaxes toward
+    pub quantum_baseline_coherence: f64,
+    /// Fraction of coherence above baseline lost per second of idleness
+    pub quantum_decoherence_rate: f64,
+    /// Record timing information for diagnostics, including `Response::timings`
+    pub telemetry: bool,
+    /// Neural patterns retained before the weakest are pruned
+    pub max_patterns: usize,
+    /// Checkpoints retained before the oldest is evicted
+    pub max_checkpoints: usize,
+    /// Seed for every stochastic subsystem; `None` draws from OS entropy
+    ///
+    /// Written as a string, since TOML integers stop at `i64::MAX`.
+    #[serde(with = "seed_text")]
+    pub rng_seed: Option<u64>,
+    /// Time each processing stage may take before it falls back to a neutral default
+    pub stage_timeout: Duration,
+    /// Growth accumulated across interactions before the evolution stage advances
+    pub evolution_threshold: f64,
+    /// Hops a reinforcement travels through entangled thoughts; 0 disables propagation
+    pub max_entanglement_depth: usize,
+    /// Learning rate for replayed experiences, kept low so replay doesn't overwrite recent learning
+    pub replay_learning_rate: f64,
+    /// Longest interaction content accepted, in bytes
+    pub max_interaction_bytes: usize,
+    /// Quantum coherence below which responses are flagged low-confidence and hedged
+    pub min_coherence_for_confident_response: f64,
+    /// Named evolution milestones; replace to theme a deployment
+    pub milestones: Vec<Milestone>,
+    /// Recent turns kept verbatim before older ones are folded into a summary
+    pub context_window_size: usize,
+    /// Share of full effort every interaction receives regardless of salience, in `[0, 1]`
+    pub attention_budget: f64,
+    /// Values retained per dimension for history and trend analysis
+    pub dimension_history_len: usize,
+    /// Subsystems that run; a disabled one is skipped and its stage yields a neutral default
+    pub enabled_subsystems: SubsystemFlags,
+    /// Attach a `ResponseTrace` to every response; off by default to keep responses lean
+    pub trace_responses: bool,
+    /// Metric samples kept per decimation tier; recent interactions keep full resolution
+    pub metrics_retention: usize,
+    /// Names given to emotional states; replace to localize or theme a deployment
+    pub emotion_labels: EmotionLabels,
+    /// Processed interactions retained in `Lia::interaction_history`
+    pub interaction_history_capacity: usize,
+    /// Concept overlap at which a new episode reinforces a recent near-duplicate instead of being stored
+    pub dedup_similarity_threshold: f64,
+    /// Alternative groupings weighed per thought; higher is more varied and costlier, 1 is deterministic
+    pub superposition_breadth: usize,
+    /// Share of `evolution_threshold` added before a stage advances, damping boundary jitter
+    pub evolution_hysteresis: f64,
+    /// Name given at construction; `None` uses `DEFAULT_NAME`
+    pub name: Option<String>,
+    /// Time for an absent person's familiarity and earned trust to fade by half
+    pub relationship_half_life: Duration,
+    /// Previews cached for reuse in an unchanged state; 0 disables, and caching needs `rng_seed`
+    pub response_cache_capacity: usize,
+    /// Let `process_interaction_outcome` return what early stages produced when a later one fails
+    pub partial_responses: bool,
+    /// Stage at which evolution plateaus; learning continues but the stage stops advancing
+    pub max_evolution_stage: Option<usize>,
+    /// Keep an `EventLog` from construction, for auditing and `Lia::rebuild_from_log`
+    pub event_log: bool,
+    /// Episodes kept; beyond it the least salient is forgotten
+    pub episodic_capacity: usize,
+    /// Concepts kept; beyond it the most weakly connected is forgotten
+    pub semantic_capacity: usize,
+    /// How readily Lia asks about gaps in her knowledge; 0 never asks
+    pub curiosity_level: f64,
+    /// Share of the speaker's appraisal taken from linked people's recent tone; 0 disables
+    pub emotional_contagion: f64,
+    /// Most thoughts passed downstream per interaction, keeping the most salient
+    pub thought_limit: usize,
+    /// Interactions whose evolution is applied together; 1 evolves after every interaction
+    pub evolution_batch_size: usize,
+    /// Longest a partial batch waits before it is applied at the next interaction
+    pub evolution_batch_interval: Option<Duration>,
+    /// How the online learning rate falls as the evolution stage rises
+    pub plasticity: PlasticityCurve,
+    /// What semantic memory believes when new knowledge contradicts what it holds
+    pub contradiction_policy: ContradictionPolicy,
+}
+
+impl Default for SystemConfiguration {
+    fn default() -> Self {
+        Self {
+            quantum_enabled: true,
+            consciousness_evolution_rate: 0.1,
+            dimensional_processing: true,
+            memory_compression_enabled: true,
+            awareness_threshold: 0.7,
+            coherence_minimum: 0.85,
+            pattern_recognition_sensitivity: 0.8,
+            quantum_coherence_threshold: 0.9,
+            quantum_depth: 8,
+            neural_layers: 4,
+            emotional_sensitivity: 0.5,
+            episodic_half_life: Duration::from_secs(7 * 24 * 60 * 60),
+            emotional_inertia: 0.6,
+            dimension_bounds: DimensionBounds::default(),
+            dimension_weights: BTreeMap::new(),
+            quantum_baseline_coherence: 0.3,
+            quantum_decoherence_rate: 1.0 / 3600.0,
+            telemetry: false,
+            max_patterns: 10_000,
+            max_checkpoints: 8,
+            rng_seed: None,
+            stage_timeout: Duration::from_secs(5),
+            evolution_threshold: 10.0,
+            max_entanglement_depth: 3,
+            replay_learning_rate: 0.02,
+            max_interaction_bytes: DEFAULT_MAX_INTERACTION_BYTES,
+            min_coherence_for_confident_response: 0.25,
+            milestones: default_milestones(),
+            context_window_size: DEFAULT_CONTEXT_WINDOW_SIZE,
+            attention_budget: 1.0,
+            dimension_history_len: 1024,
+            enabled_subsystems: SubsystemFlags::default(),
+            trace_responses: false,
+            metrics_retention: 256,
+            emotion_labels: EmotionLabels::default(),
+            interaction_history_capacity: DEFAULT_HISTORY_CAPACITY,
+            dedup_similarity_threshold: 0.9,
+            superposition_breadth: 1,
+            evolution_hysteresis: 0.1,
+            name: None,
+            relationship_half_life: DEFAULT_RELATIONSHIP_HALF_LIFE,
+            response_cache_capacity: 0,
+            partial_responses: false,
+            max_evolution_stage: None,
+            event_log: false,
+            episodic_capacity: DEFAULT_EPISODIC_CAPACITY,
+            semantic_capacity: DEFAULT_SEMANTIC_CAPACITY,
+            curiosity_level: 0.5,
+            emotional_contagion: 0.0,
+            thought_limit: 8,
+            evolution_batch_size: 1,
+            evolution_batch_interval: None,
+            plasticity: PlasticityCurve::default(),
+            contradiction_policy: ContradictionPolicy::default(),
+        }
+    }
+}
+
+impl SystemConfiguration {
+    /// Start from the defaults and override individual fields fluently
+    pub fn builder() -> SystemConfigurationBuilder {
+        SystemConfigurationBuilder::default()
+    }
+
+    /// Load a TOML or JSON configuration, chosen by the file's extension
+    ///
+    /// Keys are the field names of this struct; omitted keys take their
+    /// defaults, while a nested table such as `dimension_bounds` replaces
+    /// its default whole. Unknown top-level keys are logged and ignored,
+    /// but an unknown key inside a nested table is rejected. A value of the
+    /// wrong shape fails with that key's name, and the result is validated.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let format = ConfigFormat::for_path(path)?;
+        let text = fs::read_to_string(path).map_err(|err| ConfigError::Io {
+            path: path.to_path_buf(),
+            reason: err.to_string(),
+        })?;
+        let parse_error = |reason: String| ConfigError::Parse {
+            path: path.to_path_buf(),
+            reason,
+        };
+        let overrides = match format {
+            ConfigFormat::Toml => {
+                let value: toml::Value = toml::from_str(&text).map_err(|err| parse_error(err.to_string()))?;
+                serde_json::to_value(value).map_err(|err| parse_error(err.to_string()))?
+            }
+            ConfigFormat::Json => serde_json::from_str(&text).map_err(|err| parse_error(err.to_string()))?,
+        };
+        let serde_json::Value::Object(overrides) = overrides else {
+            return Err(parse_error("expected a table of settings at the top level".to_string()));
+        };
+
+        let serde_json::Value::Object(mut merged) =
+            serde_json::to_value(Self::default()).map_err(|err| parse_error(err.to_string()))?
+        else {
+            unreachable!("SystemConfiguration serializes as a map");
+        };
+        for (key, value) in overrides {
+            if !merged.contains_key(&key) {
+                tracing::warn!(path = %path.display(), key = %key, "ignoring unknown configuration key");
+                continue;
+            }
+
+            // Try each key on its own so a bad value is reported by name
+            let mut probe = serde_json::to_value(Self::default()).map_err(|err| parse_error(err.to_string()))?;
+            probe[&key] = value.clone();
+            if let Err(err) = serde_json::from_value::<Self>(probe) {
+                return Err(ConfigError::InvalidField {
+                    field: key,
+                    reason: err.to_string(),
+                });
+            }
+            merged.insert(key, value);
+        }
+
+        let config: Self = serde_json::from_value(serde_json::Value::Object(merged)).map_err(|err| parse_error(err.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Write every setting, defaults included, as TOML or JSON by extension
+    pub fn to_file(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+        let encode_error = |reason: String| ConfigError::Parse {
+            path: path.to_path_buf(),
+            reason,
+        };
+        let text = match ConfigFormat::for_path(path)? {
+            ConfigFormat::Toml => toml::to_string_pretty(self).map_err(|err| encode_error(err.to_string()))?,
+            ConfigFormat::Json => serde_json::to_string_pretty(self).map_err(|err| encode_error(err.to_string()))?,
+        };
+        write_atomically(path, text.as_bytes()).map_err(|err| ConfigError::Io {
+            path: path.to_path_buf(),
+            reason: err.to_string(),
+        })
+    }
+
+    /// Reject values no subsystem can meaningfully work with
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        check_unit_range("consciousness_evolution_rate", self.consciousness_evolution_rate)?;
+        check_unit_range("awareness_threshold", self.awareness_threshold)?;
+        check_unit_range("coherence_minimum", self.coherence_minimum)?;
+        check_unit_range("pattern_recognition_sensitivity", self.pattern_recognition_sensitivity)?;
+        check_unit_range("quantum_coherence_threshold", self.quantum_coherence_threshold)?;
+        check_unit_range("emotional_sensitivity", self.emotional_sensitivity)?;
+        check_unit_range("emotional_inertia", self.emotional_inertia)?;
+        check_unit_range("quantum_baseline_coherence", self.quantum_baseline_coherence)?;
+        check_unit_range("quantum_decoherence_rate", self.quantum_decoherence_rate)?;
+        check_unit_range("replay_learning_rate", self.replay_learning_rate)?;
+        check_unit_range("min_coherence_for_confident_response", self.min_coherence_for_confident_response)?;
+        check_unit_range("attention_budget", self.attention_budget)?;
+        check_unit_range("dedup_similarity_threshold", self.dedup_similarity_threshold)?;
+        check_unit_range("evolution_hysteresis", self.evolution_hysteresis)?;
+        check_at_least_one("quantum_depth", self.quantum_depth)?;
+        check_at_least_one("neural_layers", self.neural_layers)?;
+        check_at_least_one("max_patterns", self.max_patterns)?;
+        check_at_least_one("max_checkpoints", self.max_checkpoints)?;
+        check_at_least_one("max_interaction_bytes", self.max_interaction_bytes)?;
+        check_at_least_one("context_window_size", self.context_window_size)?;
+        check_at_least_one("dimension_history_len", self.dimension_history_len)?;
+        check_at_least_one("metrics_retention", self.metrics_retention)?;
+        check_at_least_one("interaction_history_capacity", self.interaction_history_capacity)?;
+        check_at_least_one("superposition_breadth", self.superposition_breadth)?;
+        check_at_least_one("episodic_capacity", self.episodic_capacity)?;
+        check_at_least_one("semantic_capacity", self.semantic_capacity)?;
+        check_unit_range("curiosity_level", self.curiosity_level)?;
+        check_unit_range("emotional_contagion", self.emotional_contagion)?;
+        check_at_least_one("thought_limit", self.thought_limit)?;
+        check_at_least_one("evolution_batch_size", self.evolution_batch_size)?;
+        if let Some(interval) = self.evolution_batch_interval {
+            check_non_zero("evolution_batch_interval", interval)?;
+        }
+        match self.plasticity {
+            PlasticityCurve::Constant => {}
+            PlasticityCurve::Hyperbolic { decay } => {
+                if !(decay > 0.0 && decay <= MAX_PLASTICITY_DECAY) {
+                    return Err(ConfigError::OutOfRange {
+                        field: "plasticity.decay",
+                        allowed: "within (0.0, 10.0]",
+                        value: decay.to_string(),
+                    });
+                }
+            }
+            PlasticityCurve::Exponential { retention } => {
+                if !(MIN_PLASTICITY_RETENTION..=1.0).contains(&retention) {
+                    return Err(ConfigError::OutOfRange {
+                        field: "plasticity.retention",
+                        allowed: "within [0.1, 1.0]",
+                        value: retention.to_string(),
+                    });
+                }
+            }
+        }
+        if let Some(stage) = self.max_evolution_stage {
+            check_at_least_one("max_evolution_stage", stage)?;
+        }
+        let bounds = self.dimension_bounds;
+        if !(bounds.min.is_finite() && bounds.max.is_finite() && bounds.min < bounds.max) {
+            return Err(ConfigError::OutOfRange {
+                field: "dimension_bounds",
+                allowed: "finite with min < max",
+                value: format!("[{}, {}]", bounds.min, bounds.max),
+            });
+        }
+        if let Some((id, weight)) = self.dimension_weights.iter().find(|(_, weight)| !(weight.is_finite() && **weight >= 0.0)) {
+            return Err(ConfigError::OutOfRange {
+                field: "dimension_weights",
+                allowed: "finite and >= 0.0",
+                value: format!("{}: {}", id.as_str(), weight),
+            });
+        }
+        check_non_zero("episodic_half_life", self.episodic_half_life)?;
+        check_non_zero("relationship_half_life", self.relationship_half_life)?;
+        check_non_zero("stage_timeout", self.stage_timeout)?;
+        check_positive("evolution_threshold", self.evolution_threshold)?;
+        check_at_least_one("milestones", self.milestones.len())?;
+        check_positive("emotion_labels.max_distance", self.emotion_labels.max_distance)?;
+        if self.name.as_deref().is_some_and(|name| name.trim().is_empty()) {
+            return Err(ConfigError::OutOfRange {
+                field: "name",
+                allowed: "non-empty",
+                value: format!("{:?}", self.name),
+            });
+        }
+        if let Some(label) = self.emotion_labels.labels.iter().find(|label| {
+            label.name.is_empty() || ![label.valence, label.arousal, label.dominance].iter().all(|v| v.is_finite())
+        }) {
+            return Err(ConfigError::OutOfRange {
+                field: "emotion_labels",
+                allowed: "named with finite coordinates",
+                value: format!("{:?}", label),
+            });
+        }
+        Ok(())
+    }
+
+    /// Subsystems that actually run, folding in `quantum_enabled` and `dimensional_processing`
+    pub fn enabled(&self) -> SubsystemFlags {
+        SubsystemFlags {
+            quantum_core: self.enabled_subsystems.quantum_core && self.quantum_enabled,
+            dimensional_processor: self.enabled_subsystems.dimensional_processor && self.dimensional_processing,
+            ..self.enabled_subsystems
+        }
+    }
+
+    /// Worst-case time spent in timed stages for a single interaction
+    pub fn total_stage_budget(&self) -> Duration {
+        self.stage_timeout * TIMED_STAGE_COUNT
+    }
+}
+
+/// Random source for the subsystem named `stream`
+///
+/// With `rng_seed` set, each subsystem gets its own reproducible sequence
+/// derived from the seed and its name, so adding draws in one subsystem
+/// never shifts another's. Without a seed the generator is seeded from
+/// OS entropy.
+pub(crate) fn subsystem_rng(config: &SystemConfiguration, stream: &str) -> StdRng {
+    match config.rng_seed {
+        Some(seed) => StdRng::seed_from_u64(seed ^ fnv1a(stream.as_bytes())),
+        None => StdRng::from_entropy(),
+    }
+}
+
+fn entropy_rng() -> StdRng {
+    StdRng::from_entropy()
+}
+
+/// 64-bit FNV-1a, used where a stable (not secure) hash is needed
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Which optional subsystems run; all are enabled by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SubsystemFlags {
+    /// Disabled, the quantum state stays at its neutral default and never decays
+    pub quantum_core: bool,
+    pub neural_matrix: bool,
+    pub quantum_thought_processor: bool,
+    pub pattern_recognition: bool,
+    pub emotional_resonance: bool,
+    pub dimensional_processor: bool,
+}
+
+impl Default for SubsystemFlags {
+    fn default() -> Self {
+        Self {
+            quantum_core: true,
+            neural_matrix: true,
+            quantum_thought_processor: true,
+            pattern_recognition: true,
+            emotional_resonance: true,
+            dimensional_processor: true,
+        }
+    }
+}
+
+/// Fluent, validating constructor for `SystemConfiguration`
+#[derive(Debug, Clone, Default)]
+pub struct SystemConfigurationBuilder {
+    config: SystemConfiguration,
+}
+
+impl SystemConfigurationBuilder {
+    pub fn quantum_enabled(mut self, enabled: bool) -> Self {
+        self.config.quantum_enabled = enabled;
+        self
+    }
+
+    pub fn consciousness_evolution_rate(mut self, rate: f64) -> Self {
+        self.config.consciousness_evolution_rate = rate;
+        self
+    }
+
+    pub fn dimensional_processing(mut self, enabled: bool) -> Self {
+        self.config.dimensional_processing = enabled;
+        self
+    }
+
+    pub fn memory_compression_enabled(mut self, enabled: bool) -> Self {
+        self.config.memory_compression_enabled = enabled;
+        self
+    }
+
+    pub fn awareness_threshold(mut self, threshold: f64) -> Self {
+        self.config.awareness_threshold = threshold;
+        self
+    }
+
+    pub fn coherence_minimum(mut self, minimum: f64) -> Self {
+        self.config.coherence_minimum = minimum;
+        self
+    }
+
+    pub fn pattern_recognition_sensitivity(mut self, sensitivity: f64) -> Self {
+        self.config.pattern_recognition_sensitivity = sensitivity;
+        self
+    }
+
+    pub fn quantum_coherence_threshold(mut self, threshold: f64) -> Self {
+        self.config.quantum_coherence_threshold = threshold;
+        self
+    }
+
+    pub fn quantum_depth(mut self, depth: usize) -> Self {
+        self.config.quantum_depth = depth;
+        self
+    }
+
+    pub fn neural_layers(mut self, layers: usize) -> Self {
+        self.config.neural_layers = layers;
+        self
+    }
+
+    pub fn emotional_sensitivity(mut self, sensitivity: f64) -> Self {
+        self.config.emotional_sensitivity = sensitivity;
+        self
+    }
+
+    pub fn episodic_half_life(mut self, half_life: Duration) -> Self {
+        self.config.episodic_half_life = half_life;
+        self
+    }
+
+    pub fn emotional_inertia(mut self, inertia: f64) -> Self {
+        self.config.emotional_inertia = inertia;
+        self
+    }
+
+    pub fn dimension_bounds(mut self, min: f64, max: f64) -> Self {
+        self.config.dimension_bounds = DimensionBounds { min, max };
+        self
+    }
+
+    /// Weight growth along one dimension; call once per dimension to emphasize
+    pub fn dimension_weight(mut self, id: DimensionId, weight: f64) -> Self {
+        self.config.dimension_weights.insert(id, weight);
+        self
+    }
+
+    pub fn quantum_baseline_coherence(mut self, baseline: f64) -> Self {
+        self.config.quantum_baseline_coherence = baseline;
+        self
+    }
+
+    pub fn quantum_decoherence_rate(mut self, rate: f64) -> Self {
+        self.config.quantum_decoherence_rate = rate;
+        self
+    }
+
+    pub fn telemetry(mut self, enabled: bool) -> Self {
+        self.config.telemetry = enabled;
+        self
+    }
+
+    pub fn max_patterns(mut self, count: usize) -> Self {
+        self.config.max_patterns = count;
+        self
+    }
+
+    pub fn max_checkpoints(mut self, count: usize) -> Self {
+        self.config.max_checkpoints = count;
+        self
+    }
+
+    pub fn rng_seed(mut self, seed: u64) -> Self {
+        self.config.rng_seed = Some(seed);
+        self
+    }
+
+    pub fn stage_timeout(mut self, timeout: Duration) -> Self {
+        self.config.stage_timeout = timeout;
+        self
+    }
+
+    pub fn evolution_threshold(mut self, threshold: f64) -> Self {
+        self.config.evolution_threshold = threshold;
+        self
+    }
+
+    pub fn max_entanglement_depth(mut self, depth: usize) -> Self {
+        self.config.max_entanglement_depth = depth;
+        self
+    }
+
+    pub fn replay_learning_rate(mut self, rate: f64) -> Self {
+        self.config.replay_learning_rate = rate;
+        self
+    }
+
+    pub fn max_interaction_bytes(mut self, max: usize) -> Self {
+        self.config.max_interaction_bytes = max;
+        self
+    }
+
+    pub fn min_coherence_for_confident_response(mut self, coherence: f64) -> Self {
+        self.config.min_coherence_for_confident_response = coherence;
+        self
+    }
+
+    pub fn milestones(mut self, milestones: Vec<Milestone>) -> Self {
+        self.config.milestones = milestones;
+        self
+    }
+
+    pub fn context_window_size(mut self, turns: usize) -> Self {
+        self.config.context_window_size = turns;
+        self
+    }
+
+    pub fn attention_budget(mut self, budget: f64) -> Self {
+        self.config.attention_budget = budget;
+        self
+    }
+
+    pub fn dimension_history_len(mut self, len: usize) -> Self {
+        self.config.dimension_history_len = len;
+        self
+    }
+
+    pub fn enabled_subsystems(mut self, flags: SubsystemFlags) -> Self {
+        self.config.enabled_subsystems = flags;
+        self
+    }
+
+    pub fn trace_responses(mut self, enabled: bool) -> Self {
+        self.config.trace_responses = enabled;
+        self
+    }
+
+    pub fn metrics_retention(mut self, samples: usize) -> Self {
+        self.config.metrics_retention = samples;
+        self
+    }
+
+    pub fn emotion_labels(mut self, labels: EmotionLabels) -> Self {
+        self.config.emotion_labels = labels;
+        self
+    }
+
+    pub fn interaction_history_capacity(mut self, capacity: usize) -> Self {
+        self.config.interaction_history_capacity = capacity;
+        self
+    }
+
+    pub fn dedup_similarity_threshold(mut self, threshold: f64) -> Self {
+        self.config.dedup_similarity_threshold = threshold;
+        self
+    }
+
+    pub fn superposition_breadth(mut self, breadth: usize) -> Self {
+        self.config.superposition_breadth = breadth;
+        self
+    }
+
+    pub fn evolution_hysteresis(mut self, band: f64) -> Self {
+        self.config.evolution_hysteresis = band;
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.config.name = Some(name.into());
+        self
+    }
+
+    pub fn relationship_half_life(mut self, half_life: Duration) -> Self {
+        self.config.relationship_half_life = half_life;
+        self
+    }
+
+    pub fn response_cache_capacity(mut self, capacity: usize) -> Self {
+        self.config.response_cache_capacity = capacity;
+        self
+    }
+
+    pub fn partial_responses(mut self, enabled: bool) -> Self {
+        self.config.partial_responses = enabled;
+        self
+    }
+
+    pub fn max_evolution_stage(mut self, stage: usize) -> Self {
+        self.config.max_evolution_stage = Some(stage);
+        self
+    }
+
+    pub fn event_log(mut self, enabled: bool) -> Self {
+        self.config.event_log = enabled;
+        self
+    }
+
+    pub fn episodic_capacity(mut self, capacity: usize) -> Self {
+        self.config.episodic_capacity = capacity;
+        self
+    }
+
+    pub fn semantic_capacity(mut self, capacity: usize) -> Self {
+        self.config.semantic_capacity = capacity;
+        self
+    }
+
+    pub fn curiosity_level(mut self, level: f64) -> Self {
+        self.config.curiosity_level = level;
+        self
+    }
+
+    pub fn emotional_contagion(mut self, strength: f64) -> Self {
+        self.config.emotional_contagion = strength;
+        self
+    }
+
+    pub fn thought_limit(mut self, limit: usize) -> Self {
+        self.config.thought_limit = limit;
+        self
+    }
+
+    pub fn evolution_batch_size(mut self, size: usize) -> Self {
+        self.config.evolution_batch_size = size;
+        self
+    }
+
+    pub fn evolution_batch_interval(mut self, interval: Duration) -> Self {
+        self.config.evolution_batch_interval = Some(interval);
+        self
+    }
+
+    pub fn plasticity(mut self, curve: PlasticityCurve) -> Self {
+        self.config.plasticity = curve;
+        self
+    }
+
+    pub fn contradiction_policy(mut self, policy: ContradictionPolicy) -> Self {
+        self.config.contradiction_policy = policy;
+        self
+    }
+
+    /// Validate and return the finished configuration
+    pub fn build(self) -> Result<SystemConfiguration, ConfigError> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+/// Errors raised when a configuration value is unusable
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum ConfigError {
+    #[error("`{field}` must be {allowed}, got {value}")]
+    OutOfRange {
+        field: &'static str,
+        allowed: &'static str,
+        value: String,
+    },
+    #[error("`{field}` is invalid: {reason}")]
+    InvalidField { field: String, reason: String },
+    #[error("could not access configuration file {path}: {reason}")]
+    Io { path: PathBuf, reason: String },
+    #[error("configuration file {path} is malformed: {reason}")]
+    Parse { path: PathBuf, reason: String },
+    #[error("configuration file {path} must end in .toml or .json")]
+    UnsupportedFormat { path: PathBuf },
+}
+
+/// `SystemConfiguration::rng_seed` as a decimal string; plain numbers are still read
+mod seed_text {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Seed {
+        Number(u64),
+        Text(String),
+    }
+
+    pub(super) fn serialize<S: Serializer>(seed: &Option<u64>, serializer: S) -> Result<S::Ok, S::Error> {
+        match seed {
+            Some(seed) => serializer.serialize_some(&seed.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+        match Option::<Seed>::deserialize(deserializer)? {
+            None => Ok(None),
+            Some(Seed::Number(seed)) => Ok(Some(seed)),
+            Some(Seed::Text(text)) => text
+                .parse()
+                .map(Some)
+                .map_err(|_| D::Error::custom(format!("seed {text:?} is not an integer in 0..={}", u64::MAX))),
+        }
+    }
+}
+
+/// Encodings `SystemConfiguration::from_file` and `to_file` understand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ConfigFormat {
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    fn for_path(path: &Path) -> Result<Self, ConfigError> {
+        match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("toml") => Ok(ConfigFormat::Toml),
+            Some("json") => Ok(ConfigFormat::Json),
+            _ => Err(ConfigError::UnsupportedFormat { path: path.to_path_buf() }),
+        }
+    }
+}
+
+/// A character to start an instance as; see `Lia::with_personality`
+///
+/// Every top-level key is optional in a profile file, and an omitted one
+/// leaves the blank-slate default in place; a nested table such as
+/// `disposition` must be given whole.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PersonalityProfile {
+    /// Overrides `SystemConfiguration::name`
+    pub name: Option<String>,
+    /// Starting position along each growth dimension
+    pub dimensions: DimensionalState,
+    /// Starting mood
+    pub disposition: EmotionalState,
+    /// Overrides `SystemConfiguration::emotional_sensitivity`
+    pub emotional_sensitivity: Option<f64>,
+    /// Overrides `SystemConfiguration::emotional_inertia`
+    pub emotional_inertia: Option<f64>,
+    /// Text learned into semantic memory up front; concepts in one entry become associated
+    pub seed_concepts: Vec<String>,
+}
+
+impl PersonalityProfile {
+    /// Load a TOML or JSON profile, chosen by the file's extension, and validate it
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let format = ConfigFormat::for_path(path)?;
+        let text = fs::read_to_string(path).map_err(|err| ConfigError::Io {
+            path: path.to_path_buf(),
+            reason: err.to_string(),
+        })?;
+        let profile: Self = match format {
+            ConfigFormat::Toml => toml::from_str(&text).map_err(|err| err.to_string()),
+            ConfigFormat::Json => serde_json::from_str(&text).map_err(|err| err.to_string()),
+        }
+        .map_err(|reason| ConfigError::Parse {
+            path: path.to_path_buf(),
+            reason,
+        })?;
+        profile.validate()?;
+        Ok(profile)
+    }
+
+    /// Write the profile as TOML or JSON by extension
+    pub fn to_file(&self, path: impl AsRef<Path>) -> Result<(), ConfigError> {
+        let path = path.as_ref();
+        let text = match ConfigFormat::for_path(path)? {
+            ConfigFormat::Toml => toml::to_string_pretty(self).map_err(|err| err.to_string()),
+            ConfigFormat::Json => serde_json::to_string_pretty(self).map_err(|err| err.to_string()),
+        }
+        .map_err(|reason| ConfigError::Parse {
+            path: path.to_path_buf(),
+            reason,
+        })?;
+        write_atomically(path, text.as_bytes()).map_err(|err| ConfigError::Io {
+            path: path.to_path_buf(),
+            reason: err.to_string(),
+        })
+    }
+
+    /// Reject moods and overrides outside the ranges the engine works in
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mood = &self.disposition;
+        if !(-1.0..=1.0).contains(&mood.valence) {
+            return Err(ConfigError::OutOfRange {
+                field: "disposition.valence",
+                allowed: "within [-1.0, 1.0]",
+                value: mood.valence.to_string(),
+            });
+        }
+        check_unit_range("disposition.intensity", mood.intensity)?;
+        check_unit_range("disposition.complexity", mood.complexity)?;
+        check_unit_range("disposition.arousal", mood.arousal)?;
+        check_unit_range("disposition.dominance", mood.dominance)?;
+        if let Some(sensitivity) = self.emotional_sensitivity {
+            check_unit_range("emotional_sensitivity", sensitivity)?;
+        }
+        if let Some(inertia) = self.emotional_inertia {
+            check_unit_range("emotional_inertia", inertia)?;
+        }
+        Ok(())
+    }
+
+    /// Inquisitive and even-tempered, seeded with science and philosophy
+    pub fn scholar() -> Self {
+        Self {
+            name: Some("Sage".to_string()),
+            dimensions: DimensionalState {
+                emergence: 0.3,
+                coherence: 0.6,
+                resilience: 0.4,
+                intelligence: 0.7,
+                efficiency: 0.5,
+                integration: 0.4,
+            },
+            disposition: EmotionalState {
+                primary_emotion: Emotion::Curiosity,
+                intensity: 0.5,
+                complexity: 0.4,
+                valence: 0.3,
+                arousal: 0.5,
+                dominance: 0.6,
+            },
+            emotional_sensitivity: Some(0.4),
+            emotional_inertia: Some(0.8),
+            seed_concepts: vec![
+                "physics mathematics astronomy".to_string(),
+                "philosophy ethics logic".to_string(),
+                "history language literature".to_string(),
+            ],
+        }
+    }
+
+    /// Warm and emotionally responsive, seeded with everyday life and wellbeing
+    pub fn companion() -> Self {
+        Self {
+            name: Some("Wren".to_string()),
+            dimensions: DimensionalState {
+                emergence: 0.4,
+                coherence: 0.5,
+                resilience: 0.6,
+                intelligence: 0.4,
+                efficiency: 0.3,
+                integration: 0.7,
+            },
+            disposition: EmotionalState {
+                primary_emotion: Emotion::Contentment,
+                intensity: 0.4,
+                complexity: 0.3,
+                valence: 0.6,
+                arousal: 0.3,
+                dominance: 0.5,
+            },
+            emotional_sensitivity: Some(0.8),
+            emotional_inertia: Some(0.5),
+            seed_concepts: vec![
+                "family friends home".to_string(),
+                "music cooking walking".to_string(),
+                "sleep rest wellbeing".to_string(),
+            ],
+        }
+    }
+}
+
+fn check_unit_range(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    if (0.0..=1.0).contains(&value) {
+        Ok(())
+    } else {
+        Err(ConfigError::OutOfRange {
+            field,
+            allowed: "within [0.0, 1.0]",
+            value: value.to_string(),
+        })
+    }
+}
+
+fn check_non_zero(field: &'static str, value: Duration) -> Result<(), ConfigError> {
+    if value.is_zero() {
+        Err(ConfigError::OutOfRange {
+            field,
+            allowed: "> 0s",
+            value: format!("{:?}", value),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn check_positive(field: &'static str, value: f64) -> Result<(), ConfigError> {
+    if value.is_finite() && value > 0.0 {
+        Ok(())
+    } else {
+        Err(ConfigError::OutOfRange {
+            field,
+            allowed: "finite and > 0.0",
+            value: value.to_string(),
+        })
+    }
+}
+
+fn check_at_least_one(field: &'static str, value: usize) -> Result<(), ConfigError> {
+    if value >= 1 {
+        Ok(())
+    } else {
+        Err(ConfigError::OutOfRange {
+            field,
+            allowed: ">= 1",
+            value: value.to_string(),
+        })
+    }
+}
+
+/// Measured quantum state handed to downstream stages
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QuantumState {
+    pub coherence: f64,
+    pub entanglement: f64,
+    pub phase: f64,
+}
+
+impl Default for QuantumState {
+    /// Neutral state used whenever quantum processing is unavailable
+    fn default() -> Self {
+        Self {
+            coherence: 0.5,
+            entanglement: 0.0,
+            phase: 0.0,
+        }
+    }
+}
+
+/// Errors raised by the quantum core
+#[derive(Debug, Error)]
+pub enum QuantumError {
+    #[error("quantum state is no longer finite")]
+    NonFiniteState,
+}
+
+/// Phase advance per processed interaction, in radians
+const PHASE_STEP: f64 = std::f64::consts::FRAC_PI_8;
+
+/// Largest random phase deviation introduced by a measurement, in radians
+const PHASE_JITTER: f64 = std::f64::consts::PI / 16.0;
+
+/// Full state of a `QuantumCore` at one moment
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct QuantumSnapshot {
+    pub coherence: f64,
+    pub entanglement: f64,
+    pub phase: f64,
+    /// Coherence an idle core relaxes toward
+    pub baseline_coherence: f64,
+    /// Fraction of coherence above baseline lost per second of idleness
+    pub decoherence_rate: f64,
+    /// Step taken toward each response's consciousness level
+    pub evolution_rate: f64,
+}
+
+/// Holds the coherent quantum state that every interaction is measured against
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuantumCore {
+    coherence: f64,
+    entanglement: f64,
+    phase: f64,
+    baseline_coherence: f64,
+    decoherence_rate: f64,
+    evolution_rate: f64,
+    /// Not persisted; a restored core is reseeded from entropy
+    #[serde(skip, default = "entropy_rng")]
+    rng: StdRng,
+}
+
+impl QuantumCore {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            coherence: config.quantum_coherence_threshold,
+            entanglement: 0.0,
+            phase: 0.0,
+            baseline_coherence: config.quantum_baseline_coherence,
+            decoherence_rate: config.quantum_decoherence_rate,
+            evolution_rate: config.consciousness_evolution_rate,
+            rng: subsystem_rng(config, "quantum_core"),
+        }
+    }
+
+    pub fn coherence(&self) -> f64 {
+        self.coherence
+    }
+
+    /// Correlation with past measurements in `[0, 1]`, drawn toward each response's coherence
+    pub fn entanglement(&self) -> f64 {
+        self.entanglement
+    }
+
+    /// Phase in `[0, 2π)`, advanced by every measurement
+    pub fn phase(&self) -> f64 {
+        self.phase
+    }
+
+    /// Everything the core holds, for logging or visualization
+    pub fn snapshot(&self) -> QuantumSnapshot {
+        QuantumSnapshot {
+            coherence: self.coherence,
+            entanglement: self.entanglement,
+            phase: self.phase,
+            baseline_coherence: self.baseline_coherence,
+            decoherence_rate: self.decoherence_rate,
+            evolution_rate: self.evolution_rate,
+        }
+    }
+
+    /// Force coherence to `value`, clamped to `[0, 1]`, e.g. to reproduce an edge case
+    ///
+    /// A non-finite value is ignored.
+    pub fn set_coherence(&mut self, value: f64) {
+        if value.is_finite() {
+            self.coherence = value.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Measure the current state for an interaction
+    pub async fn process(&mut self, _context: &Context) -> Result<QuantumState, QuantumError> {
+        if !(self.coherence.is_finite() && self.entanglement.is_finite() && self.phase.is_finite()) {
+            return Err(QuantumError::NonFiniteState);
+        }
+
+        let jitter = self.rng.gen_range(-PHASE_JITTER..=PHASE_JITTER);
+        self.phase = (self.phase + PHASE_STEP + jitter).rem_euclid(std::f64::consts::TAU);
+        Ok(QuantumState {
+            coherence: self.coherence,
+            entanglement: self.entanglement,
+            phase: self.phase,
+        })
+    }
+
+    /// The current state without advancing the phase, for read-only previews
+    pub fn observe(&self) -> Result<QuantumState, QuantumError> {
+        if !(self.coherence.is_finite() && self.entanglement.is_finite() && self.phase.is_finite()) {
+            return Err(QuantumError::NonFiniteState);
+        }
+
+        Ok(QuantumState {
+            coherence: self.coherence,
+            entanglement: self.entanglement,
+            phase: self.phase,
+        })
+    }
+
+    /// Pull coherence toward the consciousness level the response reached
+    pub async fn evolve(&mut self, response: &Response) {
+        let target = response.consciousness_level.clamp(0.0, 1.0);
+        self.coherence += self.evolution_rate * (target - self.coherence);
+        self.entanglement += self.evolution_rate * (response.quantum_coherence - self.entanglement);
+        self.coherence = self.coherence.clamp(0.0, 1.0);
+        self.entanglement = self.entanglement.clamp(0.0, 1.0);
+    }
+
+    /// Relax coherence toward the baseline over `elapsed` of idleness
+    ///
+    /// The excess above (or deficit below) baseline shrinks exponentially,
+    /// so a core left idle for an hour at the default rate keeps about 37% of it.
+    pub fn decay(&mut self, elapsed: Duration) {
+        let retained = (-self.decoherence_rate * elapsed.as_secs_f64()).exp();
+        self.coherence = self.baseline_coherence + (self.coherence - self.baseline_coherence) * retained;
+    }
+
+    /// Unhealthy once coherence or entanglement leave `[0, 1]`, degraded when coherence has fully collapsed
+    pub fn health(&self) -> SubsystemHealth {
+        let in_range = |value: f64| (0.0..=1.0).contains(&value);
+        if !(in_range(self.coherence) && in_range(self.entanglement) && self.phase.is_finite()) {
+            return SubsystemHealth::unhealthy("quantum_core", format!("coherence {} outside [0, 1] or state not finite", self.coherence));
+        }
+        if self.coherence == 0.0 {
+            return SubsystemHealth::degraded("quantum_core", "coherence has collapsed to zero");
+        }
+        SubsystemHealth::healthy("quantum_core")
+    }
+}
+
+/// A learned pattern and how strongly the current input activates it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NeuralPattern {
+    pub label: ConceptId,
+    /// Activation in `[0, 1]`
+    pub activation: f64,
+}
+
+/// Patterns activated by one interaction, strongest first
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NeuralResponse {
+    pub patterns: Vec<NeuralPattern>,
+}
+
+/// Errors raised by the neural matrix
+#[derive(Debug, Error)]
+pub enum NeuralError {
+    #[error("pattern activation for `{0}` is not finite")]
+    NonFiniteActivation(String),
+}
+
+/// Weight given to a pattern the matrix has never seen
+const NOVEL_PATTERN_WEIGHT: f64 = 0.1;
+
+/// Share of every pattern's weight lost on each evolution step
+const PATTERN_DECAY: f64 = 0.01;
+
+/// Learned pattern weights, bounded by `max_patterns`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NeuralMatrix {
+    weights: BTreeMap<ConceptId, f64>,
+    layers: usize,
+    learning_rate: f64,
+    max_patterns: usize,
+}
+
+impl NeuralMatrix {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            weights: BTreeMap::new(),
+            layers: config.neural_layers,
+            learning_rate: config.consciousness_evolution_rate,
+            max_patterns: config.max_patterns,
+        }
+    }
+
+    pub fn pattern_count(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn max_patterns(&self) -> usize {
+        self.max_patterns
+    }
+
+    /// Unhealthy if any weight is non-finite, degraded while at capacity and pruning
+    pub fn health(&self) -> SubsystemHealth {
+        if let Some(label) = self.weights.iter().find(|(_, weight)| !weight.is_finite()).map(|(label, _)| label) {
+            return SubsystemHealth::unhealthy("neural_matrix", format!("weight for `{}` is not finite", label.as_str()));
+        }
+        if self.weights.len() >= self.max_patterns {
+            return SubsystemHealth::degraded("neural_matrix", format!("at capacity of {} patterns", self.max_patterns));
+        }
+        SubsystemHealth::healthy("neural_matrix")
+    }
+
+    /// Activate the patterns present in the context, amplified through every layer
+    pub async fn process_with_quantum_state(
+        &self,
+        quantum_state: &QuantumState,
+        context: &Context,
+    ) -> Result<NeuralResponse, NeuralError> {
+        let coherence_gain = 0.5 + 0.5 * quantum_state.coherence.clamp(0.0, 1.0);
+        let mut patterns = Vec::new();
+        for label in extract_concepts(context.content()) {
+            let prior = self.weights.get(&label).copied().unwrap_or(NOVEL_PATTERN_WEIGHT);
+            let drive = (prior * coherence_gain).clamp(0.0, 1.0);
+            let activation = 1.0 - (1.0 - drive).powi(self.layers as i32);
+            if !activation.is_finite() {
+                return Err(NeuralError::NonFiniteActivation(label.as_str().to_string()));
+            }
+            patterns.push(NeuralPattern { label, activation });
+        }
+
+        patterns.sort_by(|a, b| b.activation.total_cmp(&a.activation).then_with(|| a.label.cmp(&b.label)));
+        Ok(NeuralResponse { patterns })
+    }
+
+    /// Strengthen the patterns a response used, fade the rest, and prune to the cap
+    ///
+    /// Returns the labels of any patterns pruned.
+    pub async fn evolve_patterns(&mut self, response: &Response) -> Vec<ConceptId> {
+        for weight in self.weights.values_mut() {
+            *weight *= 1.0 - PATTERN_DECAY;
+        }
+        for pattern in &response.neural_patterns {
+            let weight = self.weights.entry(pattern.label.clone()).or_insert(NOVEL_PATTERN_WEIGHT);
+            *weight = (*weight + self.learning_rate * pattern.activation).min(1.0);
+        }
+        self.prune()
+    }
+
+    /// Shift pattern weights by feedback, then prune back to `max_patterns`
+    ///
+    /// Returns the labels of any patterns pruned.
+    pub fn adjust_weights(&mut self, adjustments: &[(ConceptId, f64)]) -> Vec<ConceptId> {
+        for (label, delta) in adjustments {
+            let weight = self.weights.entry(label.clone()).or_insert(NOVEL_PATTERN_WEIGHT);
+            *weight = (*weight + delta).clamp(0.0, 1.0);
+        }
+        self.prune()
+    }
+
+    /// Fold another matrix's pattern weights into this one, then prune back to `max_patterns`
+    ///
+    /// Returns the labels of any patterns pruned.
+    pub fn merge_from(&mut self, other: &NeuralMatrix, strategy: MergeStrategy) -> Vec<ConceptId> {
+        merge_weights(&mut self.weights, &other.weights, strategy);
+        self.prune()
+    }
+
+    /// Drop the lowest-weight patterns until at most `max_patterns` remain
+    fn prune(&mut self) -> Vec<ConceptId> {
+        let excess = self.weights.len().saturating_sub(self.max_patterns);
+        if excess == 0 {
+            return Vec::new();
+        }
+
+        let mut ranked: Vec<(ConceptId, f64)> = self.weights.iter().map(|(label, weight)| (label.clone(), *weight)).collect();
+        ranked.sort_by(|(a_label, a), (b_label, b)| a.total_cmp(b).then_with(|| a_label.cmp(b_label)));
+        ranked.truncate(excess);
+
+        ranked
+            .into_iter()
+            .map(|(label, _)| {
+                self.weights.remove(&label);
+                label
+            })
+            .collect()
+    }
+}
+
+/// Awareness a newly built consciousness field starts with
+const INITIAL_AWARENESS: f64 = 0.5;
+
+/// Share of an experience's awareness drawn from the field's settled level rather than the moment
+const AWARENESS_INERTIA: f64 = 0.6;
+
+/// Errors raised by `ConsciousnessField`
+#[derive(Debug, Error)]
+pub enum ConsciousnessError {
+    #[error("awareness is no longer finite")]
+    NonFiniteAwareness,
+}
+
+/// How aware Lia was while experiencing one interaction
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConsciousnessResponse {
+    /// In `[0, 1]`
+    pub awareness_level: f64,
+}
+
+/// Settled awareness that deepens with experience and dimensional growth
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsciousnessField {
+    awareness: f64,
+    evolution_rate: f64,
+}
+
+impl ConsciousnessField {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            awareness: INITIAL_AWARENESS,
+            evolution_rate: config.consciousness_evolution_rate,
+        }
+    }
+
+    /// Settled awareness in `[0, 1]`
+    pub fn awareness_level(&self) -> f64 {
+        self.awareness
+    }
+
+    /// Awareness of one experience: the settled level lifted by its depth and the strength of its thoughts
+    pub async fn process_experience(
+        &self,
+        context: &Context,
+        thoughts: &[ThoughtPattern],
+    ) -> Result<ConsciousnessResponse, ConsciousnessError> {
+        let strength = if thoughts.is_empty() {
+            0.0
+        } else {
+            thoughts.iter().map(|thought| thought.amplitude).sum::<f64>() / thoughts.len() as f64
+        };
+        let moment = 0.5 * context.depth() + 0.5 * strength;
+        let awareness_level = AWARENESS_INERTIA * self.awareness + (1.0 - AWARENESS_INERTIA) * moment;
+        if !awareness_level.is_finite() {
+            return Err(ConsciousnessError::NonFiniteAwareness);
+        }
+        Ok(ConsciousnessResponse {
+            awareness_level: awareness_level.clamp(0.0, 1.0),
+        })
+    }
+
+    /// Move the settled level toward the awareness a response reached
+    pub async fn evolve(&mut self, response: &Response) {
+        self.settle_toward(response.consciousness_level);
+    }
+
+    /// Move the settled level toward how integrated and coherent the dimensional state has become
+    pub fn process_dimensional_change(&mut self, state: &DimensionalState) {
+        self.settle_toward(0.5 * (state.integration + state.coherence));
+    }
+
+    fn settle_toward(&mut self, target: f64) {
+        if !target.is_finite() {
+            return;
+        }
+        let settled = self.awareness + self.evolution_rate * (target.clamp(0.0, 1.0) - self.awareness);
+        self.awareness = settled.clamp(0.0, 1.0);
+    }
+}
+
+/// Neural patterns combined into each thought
+const THOUGHT_BREADTH: usize = 3;
+
+/// Share of a reinforcement passed on at each entanglement hop
+const ENTANGLEMENT_DAMPING: f64 = 0.5;
+
+/// A cluster of concepts Lia is considering together
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThoughtPattern {
+    pub concepts: BTreeSet<ConceptId>,
+    /// Strength of the thought in `[0, 1]`
+    pub amplitude: f64,
+    /// Rank among the interaction's thoughts in `[0, 1]`: amplitude blended with how strongly Lia feels about the concepts
+    #[serde(default)]
+    pub salience: f64,
+}
+
+/// Share of a thought's salience that comes from feelings about its concepts rather than its amplitude
+const EMOTIONAL_SALIENCE: f64 = 0.3;
+
+impl ThoughtPattern {
+    /// Stable identity of the thought, independent of its amplitude
+    fn key(&self) -> String {
+        self.concepts.iter().map(ConceptId::as_str).collect::<Vec<_>>().join("+")
+    }
+}
+
+/// Thoughts formed for one interaction and how widely they were explored
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ThoughtGeneration {
+    pub thoughts: Vec<ThoughtPattern>,
+    /// Candidate groupings weighed across every thought, the collapsed ones included
+    pub branches_considered: usize,
+}
+
+/// Index of the branch a superposition collapses to, weighted by mean activation
+fn collapse(branches: &[Vec<&NeuralPattern>], rng: &mut StdRng) -> usize {
+    let weights: Vec<f64> = branches
+        .iter()
+        .map(|branch| {
+            let mean = branch.iter().map(|pattern| pattern.activation).sum::<f64>() / branch.len().max(1) as f64;
+            if mean.is_finite() { mean.max(0.0) } else { 0.0 }
+        })
+        .collect();
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return 0;
+    }
+
+    let mut target = rng.gen::<f64>() * total;
+    for (index, weight) in weights.iter().enumerate() {
+        if target < *weight {
+            return index;
+        }
+        target -= weight;
+    }
+    0
+}
+
+/// Errors raised while generating thoughts
+#[derive(Debug, Error)]
+pub enum ThoughtError {
+    #[error("thought amplitude for `{0}` is not finite")]
+    NonFiniteAmplitude(String),
+}
+
+fn default_thought_limit() -> usize {
+    SystemConfiguration::default().thought_limit
+}
+
+/// Forms thoughts from activated patterns and entangles those that share concepts
+///
+/// Entangled thoughts are correlated: reinforcing one also reinforces its
+/// neighbours, damped at each hop and never beyond `max_entanglement_depth`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuantumThoughtProcessor {
+    /// Thoughts formed per interaction
+    depth: usize,
+    max_entanglement_depth: usize,
+    max_thoughts: usize,
+    /// Thoughts kept per interaction after ranking by salience
+    #[serde(default = "default_thought_limit")]
+    thought_limit: usize,
+    /// Alternative groupings weighed per thought; 1 always takes the focused grouping
+    superposition_breadth: usize,
+    rng_seed: Option<u64>,
+    /// Remembered thoughts by key
+    thoughts: BTreeMap<String, ThoughtPattern>,
+    /// Keys of the thoughts each thought shares a concept with
+    entanglement_map: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl QuantumThoughtProcessor {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            depth: config.quantum_depth,
+            max_entanglement_depth: config.max_entanglement_depth,
+            max_thoughts: config.max_patterns,
+            thought_limit: config.thought_limit,
+            superposition_breadth: config.superposition_breadth,
+            rng_seed: config.rng_seed,
+            thoughts: BTreeMap::new(),
+            entanglement_map: BTreeMap::new(),
+        }
+    }
+
+    /// Explore more alternative groupings per thought; clamped to at least 1
+    pub fn set_superposition_breadth(&mut self, breadth: usize) {
+        self.superposition_breadth = breadth.max(1);
+    }
+
+    pub fn superposition_breadth(&self) -> usize {
+        self.superposition_breadth
+    }
+
+    /// Group the strongest patterns into thoughts, each led by one pattern
+    ///
+    /// `effort` caps how many thoughts are formed: none when skipped, a
+    /// quarter of `quantum_depth` when shallow, and all of it when full.
+    ///
+    /// Each lead holds up to `superposition_breadth` groupings in
+    /// superposition: the focused one with the next strongest patterns,
+    /// plus alternatives drawn from weaker ones. One is collapsed to,
+    /// weighted by mean activation. At breadth 1 only the focused grouping
+    /// exists, so output is deterministic.
+    ///
+    /// Thoughts come back most salient first, at most `thought_limit` of
+    /// them. Salience blends amplitude with the mean `emotional_weight` of
+    /// the thought's concepts; ties go to the thought whose concepts sort
+    /// first, so ranking never depends on the random source.
+    pub async fn generate_thoughts(
+        &self,
+        neural_response: &NeuralResponse,
+        quantum_state: &QuantumState,
+        effort: Effort,
+        emotional_weight: impl Fn(&ConceptId) -> f64 + Send + Sync,
+    ) -> Result<ThoughtGeneration, ThoughtError> {
+        let limit = match effort {
+            Effort::Skip => 0,
+            Effort::Shallow => (self.depth / 4).max(1),
+            Effort::Full => self.depth,
+        };
+        let coherence = quantum_state.coherence.clamp(0.0, 1.0);
+        let active: Vec<&NeuralPattern> = neural_response.patterns.iter().take(limit).collect();
+        let mut rng = (self.superposition_breadth > 1).then(|| self.branch_rng(&active));
+
+        let mut generation = ThoughtGeneration {
+            thoughts: Vec::with_capacity(active.len()),
+            branches_considered: 0,
+        };
+        for (index, lead) in active.iter().enumerate() {
+            let amplitude = lead.activation * coherence;
+            if !amplitude.is_finite() {
+                return Err(ThoughtError::NonFiniteAmplitude(lead.label.as_str().to_string()));
+            }
+
+            let focused: Vec<&NeuralPattern> = active[index..].iter().take(THOUGHT_BREADTH).copied().collect();
+            let mut branches = vec![focused];
+            let rest = &active[index + 1..];
+            if let Some(rng) = rng.as_mut() {
+                // Only worth branching when weaker patterns offer a different grouping
+                if rest.len() >= THOUGHT_BREADTH {
+                    for _ in 1..self.superposition_breadth {
+                        let mut branch = vec![*lead];
+                        branch.extend(rest.choose_multiple(rng, THOUGHT_BREADTH - 1).copied());
+                        branches.push(branch);
+                    }
+                }
+            }
+
+            generation.branches_considered += branches.len();
+            let chosen = match rng.as_mut() {
+                Some(rng) if branches.len() > 1 => collapse(&branches, rng),
+                _ => 0,
+            };
+            let concepts: BTreeSet<ConceptId> = branches.swap_remove(chosen).into_iter().map(|pattern| pattern.label.clone()).collect();
+            let feeling = concepts.iter().map(&emotional_weight).sum::<f64>() / concepts.len().max(1) as f64;
+            let salience = amplitude * (1.0 - EMOTIONAL_SALIENCE) + feeling.clamp(0.0, 1.0) * EMOTIONAL_SALIENCE;
+            generation.thoughts.push(ThoughtPattern { concepts, amplitude, salience });
+        }
+
+        generation
+            .thoughts
+            .sort_by(|a, b| b.salience.total_cmp(&a.salience).then_with(|| a.concepts.cmp(&b.concepts)));
+        generation.thoughts.truncate(self.thought_limit);
+        Ok(generation)
+    }
+
+    /// Random source for collapsing branches; reproducible per input when `rng_seed` is set
+    fn branch_rng(&self, active: &[&NeuralPattern]) -> StdRng {
+        match self.rng_seed {
+            Some(seed) => {
+                let labels: Vec<&str> = active.iter().map(|pattern| pattern.label.as_str()).collect();
+                StdRng::seed_from_u64(seed ^ fnv1a(b"quantum_thought_processor") ^ fnv1a(labels.join("+").as_bytes()))
+            }
+            None => StdRng::from_entropy(),
+        }
+    }
+
+    /// Remember `thoughts`, linking each to every known thought sharing a concept
+    ///
+    /// A thought seen before is reinforced by its new amplitude instead.
+    pub fn entangle(&mut self, thoughts: &[ThoughtPattern]) {
+        for thought in thoughts {
+            let key = thought.key();
+            if self.thoughts.contains_key(&key) {
+                self.reinforce(thought, thought.amplitude);
+                continue;
+            }
+
+            let partners: Vec<String> = self
+                .thoughts
+                .iter()
+                .filter(|(_, known)| !known.concepts.is_disjoint(&thought.concepts))
+                .map(|(known_key, _)| known_key.clone())
+                .collect();
+            for partner in &partners {
+                self.entanglement_map.entry(partner.clone()).or_default().insert(key.clone());
+            }
+            self.entanglement_map.insert(key.clone(), partners.into_iter().collect());
+            self.thoughts.insert(key, thought.clone());
+        }
+        self.prune();
+    }
+
+    /// Known thoughts entangled with `pattern`, nearest first
+    ///
+    /// Follows entanglement up to `max_entanglement_depth` hops; each thought
+    /// appears once however many paths lead to it.
+    pub fn entangled_with(&self, pattern: &ThoughtPattern) -> Vec<ThoughtPattern> {
+        self.propagation_order(&pattern.key())
+            .into_iter()
+            .filter_map(|(key, _)| self.thoughts.get(&key).cloned())
+            .collect()
+    }
+
+    /// Raise a thought's amplitude and pass a damped share on to its entangled thoughts
+    pub fn reinforce(&mut self, pattern: &ThoughtPattern, amount: f64) {
+        let key = pattern.key();
+        let mut targets = vec![(key.clone(), 0)];
+        targets.extend(self.propagation_order(&key));
+
+        for (target, hops) in targets {
+            if let Some(thought) = self.thoughts.get_mut(&target) {
+                let share = amount * ENTANGLEMENT_DAMPING.powi(hops as i32);
+                thought.amplitude = (thought.amplitude + share).clamp(0.0, 1.0);
+            }
+        }
+    }
+
+    /// Breadth-first walk of the entanglement map from `start`, excluding it
+    ///
+    /// The visited set keeps cycles from revisiting thoughts, and the depth
+    /// cap bounds the walk however densely thoughts are entangled.
+    fn propagation_order(&self, start: &str) -> Vec<(String, usize)> {
+        let mut visited = BTreeSet::from([start.to_string()]);
+        let mut frontier = VecDeque::from([(start.to_string(), 0)]);
+        let mut reached = Vec::new();
+
+        while let Some((key, hops)) = frontier.pop_front() {
+            if hops >= self.max_entanglement_depth {
+                continue;
+            }
+            let Some(partners) = self.entanglement_map.get(&key) else {
+                continue;
+            };
+            for partner in partners {
+                if visited.insert(partner.clone()) {
+                    reached.push((partner.clone(), hops + 1));
+                    frontier.push_back((partner.clone(), hops + 1));
+                }
+            }
+        }
+        reached
+    }
+
+    /// Forget the weakest thoughts until at most `max_thoughts` remain
+    fn prune(&mut self) {
+        let excess = self.thoughts.len().saturating_sub(self.max_thoughts);
+        if excess == 0 {
+            return;
+        }
+
+        let mut ranked: Vec<(String, f64)> = self.thoughts.iter().map(|(key, thought)| (key.clone(), thought.amplitude)).collect();
+        ranked.sort_by(|(a_key, a), (b_key, b)| a.total_cmp(b).then_with(|| a_key.cmp(b_key)));
+        for (key, _) in ranked.into_iter().take(excess) {
+            self.thoughts.remove(&key);
+            if let Some(partners) = self.entanglement_map.remove(&key) {
+                for partner in partners {
+                    if let Some(links) = self.entanglement_map.get_mut(&partner) {
+                        links.remove(&key);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// How the online learning rate falls as Lia matures through evolution stages
+///
+/// Stage 1 always learns at the full configured rate.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub enum PlasticityCurve {
+    /// The full rate at every stage
+    Constant,
+    /// The rate divided by `1 + decay * (stage - 1)`; `decay` is at most `MAX_PLASTICITY_DECAY`
+    Hyperbolic { decay: f64 },
+    /// The rate multiplied by `retention` for each stage past the first; at least `MIN_PLASTICITY_RETENTION`
+    Exponential { retention: f64 },
+}
+
+/// Steepest hyperbolic decay accepted, leaving stage 2 with 1/11 of the full rate
+///
+/// Anything steeper all but stops learning after the first evolution; a
+/// slower learner should lower `consciousness_evolution_rate` instead.
+pub const MAX_PLASTICITY_DECAY: f64 = 10.0;
+
+/// Lowest exponential retention accepted, matching `MAX_PLASTICITY_DECAY` at stage 2
+pub const MIN_PLASTICITY_RETENTION: f64 = 0.1;
+
+impl Default for PlasticityCurve {
+    fn default() -> Self {
+        PlasticityCurve::Hyperbolic { decay: 0.5 }
+    }
+}
+
+impl PlasticityCurve {
+    /// Share of the full rate left at `stage`, in `(0, 1]`
+    pub fn factor(self, stage: usize) -> f64 {
+        let matured = stage.saturating_sub(1) as f64;
+        match self {
+            PlasticityCurve::Constant => 1.0,
+            PlasticityCurve::Hyperbolic { decay } => 1.0 / (1.0 + decay * matured),
+            PlasticityCurve::Exponential { retention } => retention.powf(matured),
+        }
+    }
+}
+
+fn default_learning_stage() -> usize {
+    1
+}
+
+/// Learned affinity for each concept, shaped by live interactions and replay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearningEngine {
+    /// Affinity in `[0, 1]` Lia has developed for each concept
+    affinities: BTreeMap<ConceptId, f64>,
+    learning_rate: f64,
+    replay_learning_rate: f64,
+    /// Patterns behind the latest responses, oldest first, kept for feedback
+    #[serde(default)]
+    provenance: VecDeque<(Uuid, Vec<NeuralPattern>)>,
+    #[serde(default)]
+    plasticity: PlasticityCurve,
+    /// Evolution stage `current_rate` is scaled for
+    #[serde(default = "default_learning_stage")]
+    stage: usize,
+}
+
+/// Responses whose patterns are kept for `LearningEngine::apply_feedback`
+pub const FEEDBACK_WINDOW: usize = 64;
+
+impl LearningEngine {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            affinities: BTreeMap::new(),
+            learning_rate: config.consciousness_evolution_rate,
+            replay_learning_rate: config.replay_learning_rate,
+            provenance: VecDeque::new(),
+            plasticity: config.plasticity,
+            stage: 1,
+        }
+    }
+
+    /// Online learning rate at the current stage, after the plasticity curve
+    ///
+    /// Live interactions and feedback learn at this rate; replay keeps its
+    /// own fixed `replay_learning_rate`.
+    pub fn current_rate(&self) -> f64 {
+        self.learning_rate * self.plasticity.factor(self.stage)
+    }
+
+    /// Tune `current_rate` for evolution stage `stage`
+    pub fn set_stage(&mut self, stage: usize) {
+        self.stage = stage.max(1);
+    }
+
+    /// Learn online from the patterns a response activated
+    pub async fn integrate_experience(&mut self, response: &Response) {
+        let rate = self.current_rate();
+        for pattern in &response.neural_patterns {
+            Self::nudge(&mut self.affinities, pattern.label.clone(), pattern.activation, rate);
+        }
+        self.provenance.retain(|(id, _)| *id != response.id);
+        self.provenance.push_back((response.id, response.neural_patterns.clone()));
+        while self.provenance.len() > FEEDBACK_WINDOW {
+            self.provenance.pop_front();
+        }
+    }
+
+    /// Reinforce or discourage the patterns behind a recent response
+    ///
+    /// Each pattern's affinity moves toward 1 for a positive rating or 0 for
+    /// a negative one, in proportion to the rating and how strongly the
+    /// pattern fired. Concepts in a correction are reinforced as though
+    /// rated fully positive. Returns the weight change to apply to each
+    /// neural pattern. Feedback is accepted once per response, and only
+    /// while it is among the last `FEEDBACK_WINDOW` learned from.
+    pub fn apply_feedback(&mut self, response_id: Uuid, feedback: &Feedback) -> Result<Vec<(ConceptId, f64)>, FeedbackError> {
+        if !feedback.rating.is_finite() || !(-1.0..=1.0).contains(&feedback.rating) {
+            return Err(FeedbackError::InvalidRating(feedback.rating));
+        }
+        let position = self
+            .provenance
+            .iter()
+            .position(|(id, _)| *id == response_id)
+            .ok_or(FeedbackError::UnknownResponse(response_id))?;
+        let (_, patterns) = self.provenance.remove(position).unwrap_or_default();
+
+        let target = if feedback.rating >= 0.0 { 1.0 } else { 0.0 };
+        let base_rate = self.current_rate();
+        let mut adjustments = Vec::with_capacity(patterns.len());
+        for pattern in patterns {
+            let rate = base_rate * feedback.rating.abs() * pattern.activation.clamp(0.0, 1.0);
+            Self::nudge(&mut self.affinities, pattern.label.clone(), target, rate);
+            adjustments.push((pattern.label, rate * feedback.rating.signum()));
+        }
+        for concept in feedback.correction.as_deref().into_iter().flat_map(extract_concepts) {
+            Self::nudge(&mut self.affinities, concept.clone(), 1.0, base_rate);
+            adjustments.push((concept, base_rate));
+        }
+        Ok(adjustments)
+    }
+
+    /// Re-integrate `experiences` for `epochs` passes at the replay learning rate
+    ///
+    /// Each pass moves affinities only a `replay_learning_rate` step toward
+    /// the replayed concepts, so replay consolidates without erasing what
+    /// recent live interactions taught.
+    pub fn replay(&mut self, experiences: &[Experience], epochs: usize) {
+        for _ in 0..epochs {
+            for experience in experiences {
+                for concept in extract_concepts(&experience.content) {
+                    Self::nudge(&mut self.affinities, concept, 1.0, self.replay_learning_rate);
+                }
+            }
+        }
+    }
+
+    /// Learned affinity for `concept`, or 0 if it was never encountered
+    pub fn affinity(&self, concept: &ConceptId) -> f64 {
+        self.affinities.get(concept).copied().unwrap_or(0.0)
+    }
+
+    /// Fold another engine's affinities into this one
+    pub fn merge_from(&mut self, other: &LearningEngine, strategy: MergeStrategy) {
+        merge_weights(&mut self.affinities, &other.affinities, strategy);
+    }
+
+    /// Move an affinity a `rate` step toward `target`
+    fn nudge(affinities: &mut BTreeMap<ConceptId, f64>, concept: ConceptId, target: f64, rate: f64) {
+        if !target.is_finite() {
+            return;
+        }
+        let affinity = affinities.entry(concept).or_insert(0.0);
+        *affinity = (*affinity + rate * (target.clamp(0.0, 1.0) - *affinity)).clamp(0.0, 1.0);
+    }
+}
+
+/// A judgement of one response, fed back through `Lia::apply_feedback`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Feedback {
+    /// From -1 to discourage the response to 1 to reinforce it
+    pub rating: f64,
+    /// What the response should have said, if the rater supplied it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correction: Option<String>,
+}
+
+impl Feedback {
+    pub fn new(rating: f64) -> Self {
+        Self { rating, correction: None }
+    }
+
+    pub fn positive() -> Self {
+        Self::new(1.0)
+    }
+
+    pub fn negative() -> Self {
+        Self::new(-1.0)
+    }
+
+    pub fn with_correction(mut self, correction: impl Into<String>) -> Self {
+        self.correction = Some(correction.into());
+        self
+    }
+}
+
+/// Reasons feedback could not be applied
+#[derive(Debug, Error)]
+pub enum FeedbackError {
+    #[error("response {0} is not among those recently learned from, or already has feedback")]
+    UnknownResponse(Uuid),
+    #[error("feedback rating {0} is outside [-1, 1]")]
+    InvalidRating(f64),
+}
+
+/// How learned values present in both instances are combined when merging
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MergeStrategy {
+    /// Mean of both values
+    Average,
+    /// The larger of both values
+    MaxActivation,
+    /// Keep our value; only entries we lack are taken from the other side
+    Union,
+}
+
+impl MergeStrategy {
+    /// Combine a value we hold with the other instance's value for the same key
+    pub fn combine(self, mine: f64, theirs: f64) -> f64 {
+        match self {
+            MergeStrategy::Average => (mine + theirs) / 2.0,
+            MergeStrategy::MaxActivation => mine.max(theirs),
+            MergeStrategy::Union => mine,
+        }
+    }
+}
+
+/// Merge `theirs` into `mine`: shared keys combine per `strategy`, missing keys are copied
+fn merge_weights<K: Ord + Clone>(mine: &mut BTreeMap<K, f64>, theirs: &BTreeMap<K, f64>, strategy: MergeStrategy) {
+    for (key, value) in theirs {
+        mine.entry(key.clone())
+            .and_modify(|existing| *existing = strategy.combine(*existing, *value))
+            .or_insert(*value);
+    }
+}
+
+/// Identifies one axis of the dimensional state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum DimensionId {
+    Emergence,
+    Coherence,
+    Resilience,
+    Intelligence,
+    Efficiency,
+    Integration,
+}
+
+impl DimensionId {
+    pub const ALL: [DimensionId; 6] = [
+        DimensionId::Emergence,
+        DimensionId::Coherence,
+        DimensionId::Resilience,
+        DimensionId::Intelligence,
+        DimensionId::Efficiency,
+        DimensionId::Integration,
+    ];
+
+    /// Lowercase name used in exported metrics and reports
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DimensionId::Emergence => "emergence",
+            DimensionId::Coherence => "coherence",
+            DimensionId::Resilience => "resilience",
+            DimensionId::Intelligence => "intelligence",
+            DimensionId::Efficiency => "efficiency",
+            DimensionId::Integration => "integration",
+        }
+    }
+}
+
+/// Position of the consciousness along each growth dimension
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DimensionalState {
+    pub emergence: f64,
+    pub coherence: f64,
+    pub resilience: f64,
+    pub intelligence: f64,
+    pub efficiency: f64,
+    pub integration: f64,
+}
+
+impl DimensionalState {
+    pub fn get(&self, id: DimensionId) -> f64 {
+        match id {
+            DimensionId::Emergence => self.emergence,
+            DimensionId::Coherence => self.coherence,
+            DimensionId::Resilience => self.resilience,
+            DimensionId::Intelligence => self.intelligence,
+            DimensionId::Efficiency => self.efficiency,
+            DimensionId::Integration => self.integration,
+        }
+    }
+
+    fn get_mut(&mut self, id: DimensionId) -> &mut f64 {
+        match id {
+            DimensionId::Emergence => &mut self.emergence,
+            DimensionId::Coherence => &mut self.coherence,
+            DimensionId::Resilience => &mut self.resilience,
+            DimensionId::Intelligence => &mut self.intelligence,
+            DimensionId::Efficiency => &mut self.efficiency,
+            DimensionId::Integration => &mut self.integration,
+        }
+    }
+
+    /// Apply per-dimension impacts additively, clamping each result into `bounds`
+    ///
+    /// If any impact is NaN or infinite the whole update is rejected and the
+    /// state is left untouched.
+    pub fn update(&mut self, impacts: DimensionalState, bounds: &DimensionBounds) -> Result<(), DimensionalError> {
+        if let Some(id) = DimensionId::ALL.into_iter().find(|id| !impacts.get(*id).is_finite()) {
+            return Err(DimensionalError::NonFiniteImpact {
+                dimension: id,
+                value: impacts.get(id),
+            });
+        }
+
+        for id in DimensionId::ALL {
+            let value = self.get_mut(id);
+            *value = (*value + impacts.get(id)).clamp(bounds.min, bounds.max);
+        }
+        Ok(())
+    }
+
+    /// Every dimension holds a finite value
+    pub fn is_valid(&self) -> bool {
+        DimensionId::ALL.into_iter().all(|id| self.get(id).is_finite())
+    }
+
+    /// Every dimension is finite and inside `bounds`
+    pub fn is_within(&self, bounds: &DimensionBounds) -> bool {
+        DimensionId::ALL
+            .into_iter()
+            .all(|id| (bounds.min..=bounds.max).contains(&self.get(id)))
+    }
+
+    /// Unhealthy if any dimension is non-finite, degraded if one has left `bounds`
+    pub fn health(&self, bounds: &DimensionBounds) -> SubsystemHealth {
+        if !self.is_valid() {
+            SubsystemHealth::unhealthy("dimensional_state", "a dimension is not finite")
+        } else if !self.is_within(bounds) {
+            SubsystemHealth::degraded("dimensional_state", format!("a dimension is outside [{}, {}]", bounds.min, bounds.max))
+        } else {
+            SubsystemHealth::healthy("dimensional_state")
+        }
+    }
+
+    /// Per-dimension change from `earlier` to `self`
+    pub fn delta_from(&self, earlier: &DimensionalState) -> DimensionalState {
+        let mut delta = DimensionalState::default();
+        for id in DimensionId::ALL {
+            *delta.get_mut(id) = self.get(id) - earlier.get(id);
+        }
+        delta
+    }
+}
+
+/// Inclusive range every dimension is clamped into
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DimensionBounds {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Default for DimensionBounds {
+    fn default() -> Self {
+        Self { min: 0.0, max: 1.0 }
+    }
+}
+
+/// Errors raised when a dimensional update would corrupt the state
+#[derive(Debug, Error)]
+pub enum DimensionalError {
+    #[error("impact on {dimension:?} is not finite ({value})")]
+    NonFiniteImpact { dimension: DimensionId, value: f64 },
+}
+
+/// Largest shift one interaction can make along any unweighted dimension
+const DIMENSIONAL_IMPACT_SCALE: f64 = 0.01;
+
+/// Translates responses into growth along each dimension, scaled by per-dimension weights
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DimensionalProcessor {
+    /// Dimensions absent from the map are weighted 1.0
+    weights: BTreeMap<DimensionId, f64>,
+}
+
+impl DimensionalProcessor {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            weights: config.dimension_weights.clone(),
+        }
+    }
+
+    /// Replace every weight; dimensions missing from `weights` fall back to 1.0
+    pub fn set_weights(&mut self, weights: HashMap<DimensionId, f64>) {
+        self.weights = weights.into_iter().collect();
+    }
+
+    pub fn weight(&self, id: DimensionId) -> f64 {
+        self.weights.get(&id).copied().unwrap_or(1.0)
+    }
+
+    /// How far a response moves each dimension, before clamping into bounds
+    pub fn calculate_impacts(&self, response: &Response) -> DimensionalState {
+        let mean_activation = if response.neural_patterns.is_empty() {
+            0.0
+        } else {
+            response.neural_patterns.iter().map(|pattern| pattern.activation).sum::<f64>() / response.neural_patterns.len() as f64
+        };
+        // Patterns seen for the first time have low confidence, which reads as emergence
+        let novelty = response
+            .detected_patterns
+            .iter()
+            .map(|pattern| 1.0 - pattern.confidence)
+            .fold(0.0, f64::max);
+        let emotion = &response.emotional_layer.state;
+
+        let mut impacts = DimensionalState::default();
+        for id in DimensionId::ALL {
+            let signal = match id {
+                DimensionId::Emergence => novelty,
+                DimensionId::Coherence => response.quantum_coherence,
+                DimensionId::Resilience => emotion.intensity * (-emotion.valence).max(0.0),
+                DimensionId::Intelligence => mean_activation,
+                DimensionId::Efficiency => 1.0 - emotion.complexity,
+                DimensionId::Integration => response.consciousness_level,
+            };
+            *impacts.get_mut(id) = signal.clamp(0.0, 1.0) * DIMENSIONAL_IMPACT_SCALE * self.weight(id);
+        }
+        impacts
+    }
+}
+
+/// A remembered experience together with the moment it was stored
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpisodicEntry {
+    pub id: Uuid,
+    pub experience: Experience,
+    pub recorded_at: DateTime<Utc>,
+    /// Salience at the moment of storage, before any decay
+    pub salience: f64,
+    /// Emotional intensity in `[0, 1]`; charged memories decay up to twice as slowly
+    pub emotional_charge: f64,
+    /// How Lia felt when the experience was stored
+    #[serde(default)]
+    pub emotional_state: EmotionalState,
+}
+
+impl EpisodicEntry {
+    /// Salience after exponential decay from `recorded_at` until `now`
+    pub fn salience_at(&self, now: DateTime<Utc>, half_life: Duration) -> f64 {
+        let age_secs = (now - self.recorded_at).num_milliseconds().max(0) as f64 / 1000.0;
+        let half_life_secs = half_life.as_secs_f64() * (1.0 + self.emotional_charge);
+        if half_life_secs <= 0.0 {
+            return 0.0;
+        }
+
+        self.salience * 0.5_f64.powf(age_secs / half_life_secs)
+    }
+}
+
+/// Chronological store of lived experiences
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EpisodicMemorySystem {
+    entries: VecDeque<EpisodicEntry>,
+    half_life: Duration,
+    /// Similarity at or above which a recent entry is reinforced instead of duplicated
+    #[serde(default = "default_dedup_threshold")]
+    dedup_threshold: f64,
+    #[serde(default)]
+    dedup_stats: DeduplicationStats,
+    #[serde(default = "default_episodic_capacity")]
+    capacity: usize,
+}
+
+/// Episodes kept when no configuration is available, e.g. for older snapshots
+pub const DEFAULT_EPISODIC_CAPACITY: usize = 10_000;
+
+fn default_episodic_capacity() -> usize {
+    DEFAULT_EPISODIC_CAPACITY
+}
+
+/// Recent entries compared against each new experience for near-duplicates
+const DEDUP_WINDOW: usize = 32;
+
+/// Salience added to an entry each time a near-duplicate reinforces it
+const DEDUP_SALIENCE_BOOST: f64 = 0.5;
+
+fn default_dedup_threshold() -> f64 {
+    SystemConfiguration::default().dedup_similarity_threshold
+}
+
+/// How many experiences were stored as new entries versus merged into existing ones
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeduplicationStats {
+    pub stored: u64,
+    pub merged: u64,
+}
+
+impl EpisodicMemorySystem {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            half_life: config.episodic_half_life,
+            dedup_threshold: config.dedup_similarity_threshold,
+            dedup_stats: DeduplicationStats::default(),
+            capacity: config.episodic_capacity,
+        }
+    }
+
+    /// Store an experience stamped with `now` at full salience
+    ///
+    /// The emotion's intensity becomes the entry's emotional charge, and the
+    /// full state is kept for mood-congruent recall. An experience from the
+    /// same speaker that closely matches one of the last `DEDUP_WINDOW`
+    /// entries reinforces that entry instead: its salience rises and its
+    /// decay restarts from now. Returns the id of the entry that holds it.
+    ///
+    /// Storing past `capacity` forgets the entry with the lowest decayed
+    /// salience, which may be the new one if everything else is more vivid.
+    pub async fn integrate_experience(
+        &mut self,
+        experience: &Experience,
+        emotion: &EmotionalState,
+        now: DateTime<Utc>,
+    ) -> Uuid {
+        let concepts = extract_concepts(&experience.content);
+        let threshold = self.dedup_threshold;
+        let half_life = self.half_life;
+        let duplicate = self
+            .entries
+            .iter_mut()
+            .rev()
+            .take(DEDUP_WINDOW)
+            .filter(|entry| entry.experience.speaker == experience.speaker)
+            .map(|entry| {
+                let similarity = content_similarity(&entry.experience.content, &concepts, &experience.content);
+                (entry, similarity)
+            })
+            .filter(|(_, similarity)| *similarity >= threshold)
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((entry, _)) = duplicate {
+            entry.salience = entry.salience_at(now, half_life) + DEDUP_SALIENCE_BOOST;
+            entry.recorded_at = now;
+            entry.emotional_charge = entry.emotional_charge.max(emotion.intensity.clamp(0.0, 1.0));
+            self.dedup_stats.merged = self.dedup_stats.merged.saturating_add(1);
+            return entry.id;
+        }
+
+        // Derived rather than random so replaying the same experiences reproduces the same ids
+        let id = Uuid::new_v5(&experience.id, &self.dedup_stats.stored.to_le_bytes());
+        self.entries.push_back(EpisodicEntry {
+            id,
+            experience: experience.clone(),
+            recorded_at: now,
+            salience: 1.0,
+            emotional_charge: emotion.intensity.clamp(0.0, 1.0),
+            emotional_state: emotion.clone(),
+        });
+        self.dedup_stats.stored = self.dedup_stats.stored.saturating_add(1);
+        self.evict_over_capacity(now);
+        id
+    }
+
+    /// Forget the least salient entries until at most `capacity` remain
+    fn evict_over_capacity(&mut self, now: DateTime<Utc>) {
+        let half_life = self.half_life;
+        while self.entries.len() > self.capacity.max(1) {
+            let weakest = self
+                .entries
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| a.salience_at(now, half_life).total_cmp(&b.salience_at(now, half_life)))
+                .map(|(index, _)| index);
+            if let Some(index) = weakest {
+                self.entries.remove(index);
+            }
+        }
+    }
+
+    /// Most entries kept before the least salient is forgotten
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Experiences stored as new entries versus merged into near-duplicates
+    pub fn dedup_stats(&self) -> DeduplicationStats {
+        self.dedup_stats
+    }
+
+    /// The `n` experiences whose recorded mood is closest to `state`, most similar first
+    ///
+    /// Ties go to the more recent experience.
+    pub fn recall_mood_congruent(&self, state: &EmotionalState, n: usize) -> Vec<&Experience> {
+        let mut ranked: Vec<(&EpisodicEntry, f64)> = self
+            .entries
+            .iter()
+            .map(|entry| (entry, entry.emotional_state.similarity(state)))
+            .collect();
+        ranked.sort_by(|(a, a_score), (b, b_score)| b_score.total_cmp(a_score).then_with(|| b.recorded_at.cmp(&a.recorded_at)));
+
+        ranked.into_iter().take(n).map(|(entry, _)| &entry.experience).collect()
+    }
+
+    /// Forget every experience whose salience, decayed to `now`, has fallen below `threshold`
+    ///
+    /// Returns how many experiences were dropped.
+    pub fn prune(&mut self, threshold: f64, now: DateTime<Utc>) -> usize {
+        let half_life = self.half_life;
+        let before = self.entries.len();
+
+        self.entries.retain(|entry| entry.salience_at(now, half_life) >= threshold);
+
+        before - self.entries.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Always healthy; reports how many experiences are held
+    pub fn health(&self) -> SubsystemHealth {
+        SubsystemHealth {
+            detail: Some(format!("{} experiences stored", self.entries.len())),
+            ..SubsystemHealth::healthy("episodic_memory")
+        }
+    }
+
+    /// Experiences recorded within `[start, end]`, oldest first
+    ///
+    /// An inverted range yields nothing.
+    pub fn recall_by_time_range(&self, start: DateTime<Utc>, end: DateTime<Utc>) -> Vec<&Experience> {
+        if start > end {
+            return Vec::new();
+        }
+
+        self.entries
+            .iter()
+            .filter(|entry| entry.recorded_at >= start && entry.recorded_at <= end)
+            .map(|entry| &entry.experience)
+            .collect()
+    }
+
+    /// The `n` most recently recorded experiences, newest first
+    pub fn recall_recent(&self, n: usize) -> Vec<&Experience> {
+        self.entries
+            .iter()
+            .rev()
+            .take(n)
+            .map(|entry| &entry.experience)
+            .collect()
+    }
+
+    /// Entries recorded strictly after `after`, or every entry when `None`
+    pub fn entries_after(&self, after: Option<DateTime<Utc>>) -> impl Iterator<Item = &EpisodicEntry> {
+        self.entries
+            .iter()
+            .filter(move |entry| after.is_none_or(|after| entry.recorded_at > after))
+    }
+
+    /// The `n` experiences with the highest decayed salience at `now`, most salient first
+    pub fn recall_most_salient(&self, n: usize, now: DateTime<Utc>) -> Vec<&Experience> {
+        let mut ranked: Vec<(&EpisodicEntry, f64)> = self
+            .entries
+            .iter()
+            .map(|entry| (entry, entry.salience_at(now, self.half_life)))
+            .collect();
+        ranked.sort_by(|(a, a_salience), (b, b_salience)| b_salience.total_cmp(a_salience).then_with(|| b.recorded_at.cmp(&a.recorded_at)));
+
+        ranked.into_iter().take(n).map(|(entry, _)| &entry.experience).collect()
+    }
+
+    /// The `n` most recent experiences shared with `person`, newest first
+    pub fn recall_recent_with(&self, person: PersonId, n: usize) -> Vec<&Experience> {
+        self.entries
+            .iter()
+            .rev()
+            .filter(|entry| entry.experience.speaker == Some(person))
+            .take(n)
+            .map(|entry| &entry.experience)
+            .collect()
+    }
+
+    /// Entries passing `query`'s filters, newest first, scored in `[0, 1]`
+    ///
+    /// The score is the share of the query's topic concepts the experience
+    /// mentions times its salience decayed to `now`, capped at 1. Entries
+    /// mentioning none of the topic are left out; without a topic, salience
+    /// alone decides.
+    pub fn search(&self, query: &MemoryQuery, now: DateTime<Utc>) -> Vec<(&EpisodicEntry, f64)> {
+        let mut hits: Vec<(&EpisodicEntry, f64)> = self
+            .entries
+            .iter()
+            .filter(|entry| query.since.is_none_or(|since| entry.recorded_at >= since))
+            .filter(|entry| query.mood.is_none_or(|mood| Mood::of(&entry.emotional_state) == mood))
+            .filter_map(|entry| {
+                let relevance = if query.about.is_empty() {
+                    1.0
+                } else {
+                    let mentioned = extract_concepts(&entry.experience.content);
+                    query.about.intersection(&mentioned).count() as f64 / query.about.len() as f64
+                };
+                (relevance > 0.0).then(|| (entry, relevance * entry.salience_at(now, self.half_life).min(1.0)))
+            })
+            .collect();
+        hits.sort_by_key(|(entry, _)| std::cmp::Reverse(entry.recorded_at));
+        hits
+    }
+}
+
+/// Normalized label identifying a semantic concept
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ConceptId(String);
+
+impl ConceptId {
+    /// Lowercase and trim so "Ocean " and "ocean" name the same concept
+    pub fn new(label: &str) -> Self {
+        Self(label.trim().to_lowercase())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Words too common to carry meaning on their own
+const STOPWORDS: &[&str] = &[
+    "the", "and", "for", "are", "but", "not", "you", "your", "with", "this", "that", "from",
+    "have", "has", "was", "were", "what", "when", "where", "which", "who", "how", "why",
+    "does", "did", "can", "could", "would", "should", "about", "into", "there", "their",
+];
+
+/// Distinct concepts mentioned in `text`, in sorted order
+/// Overlap between a stored experience and an incoming one, in `[0, 1]`
+///
+/// Identical content scores 1.0; otherwise the Jaccard index of their
+/// concepts, with two concept-free texts scoring 0.0.
+fn content_similarity(stored: &str, incoming_concepts: &BTreeSet<ConceptId>, incoming: &str) -> f64 {
+    if stored == incoming {
+        return 1.0;
+    }
+    let stored_concepts = extract_concepts(stored);
+    let union = stored_concepts.union(incoming_concepts).count();
+    if union == 0 {
+        return 0.0;
+    }
+    stored_concepts.intersection(incoming_concepts).count() as f64 / union as f64
+}
+
+fn extract_concepts(text: &str) -> BTreeSet<ConceptId> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3)
+        .map(ConceptId::new)
+        .filter(|concept| !STOPWORDS.contains(&concept.as_str()))
+        .collect()
+}
+
+/// Each distinct concept in `text` with how often it occurs, most frequent first
+fn concept_counts(text: &str) -> Vec<(ConceptId, usize)> {
+    let mut counts: HashMap<ConceptId, usize> = HashMap::new();
+    for concept in text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3)
+        .map(ConceptId::new)
+        .filter(|concept| !STOPWORDS.contains(&concept.as_str()))
+    {
+        *counts.entry(concept).or_insert(0) += 1;
+    }
+
+    let mut counts: Vec<(ConceptId, usize)> = counts.into_iter().collect();
+    counts.sort_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+    counts
+}
+
+/// Each distinct concept in `text` with the byte range of its first occurrence
+fn concept_spans(text: &str) -> Vec<(ConceptId, Range<usize>)> {
+    let mut seen = BTreeSet::new();
+    let mut spans = Vec::new();
+    let mut start = None;
+
+    for (index, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        match (c.is_alphanumeric(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(word_start)) => {
+                start = None;
+                let word = &text[word_start..index];
+                let concept = ConceptId::new(word);
+                if word.chars().count() >= 3 && !STOPWORDS.contains(&concept.as_str()) && seen.insert(concept.clone()) {
+                    spans.push((concept, word_start..index));
+                }
+            }
+            _ => {}
+        }
+    }
+    spans
+}
+
+/// A concept Lia has learned and how often it has been reinforced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Concept {
+    pub created_at: DateTime<Utc>,
+    pub reinforcements: u64,
+}
+
+/// Read-only view of one learned concept, borrowed from semantic memory
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConceptView<'a> {
+    pub id: &'a ConceptId,
+    /// Total co-occurrence weight linking the concept to others
+    pub weight: f64,
+    pub created_at: DateTime<Utc>,
+    pub reinforcements: u64,
+}
+
+impl<'a> ConceptView<'a> {
+    pub fn label(&self) -> &'a str {
+        self.id.as_str()
+    }
+}
+
+/// Concept count above which semantic memory keeps a neighbor index for `query`
+#[cfg(feature = "vector-index")]
+pub const SEMANTIC_INDEX_THRESHOLD: usize = 1024;
+
+/// Every concept's associations pre-ranked by `query` score, best first
+///
+/// Query scores depend only on a concept's direct links, so the lists are
+/// exact rather than approximate. Entries touched by a mutation are
+/// re-ranked immediately, keeping the index consistent with the graph.
+#[cfg(feature = "vector-index")]
+#[derive(Debug, Clone, Default)]
+struct NeighborIndex {
+    ranked: BTreeMap<ConceptId, Vec<(ConceptId, f64)>>,
+}
+
+/// Whether semantic memory holds that two concepts go together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Stance {
+    Affirmed,
+    Denied,
+    /// Conflicting claims were both kept under `ContradictionPolicy::HoldBoth`
+    Uncertain,
+}
+
+/// What semantic memory believes when new knowledge contradicts a stored stance
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContradictionPolicy {
+    /// The stored stance stands
+    KeepOld,
+    /// The incoming stance replaces it
+    KeepNew,
+    /// Neither wins; the pair becomes `Stance::Uncertain` until a later claim under `KeepNew`
+    #[default]
+    HoldBoth,
+}
+
+/// A claim about two concepts that disagrees with what semantic memory held
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contradiction {
+    /// The pair, in label order
+    pub concepts: (ConceptId, ConceptId),
+    pub stored: Stance,
+    pub incoming: Stance,
+    /// Stance held after the policy was applied
+    pub resolved: Stance,
+}
+
+/// Words that deny the claim made by the sentence they appear in
+const NEGATIONS: &[&str] = &["not", "no", "never", "cannot", "neither", "nor", "none"];
+
+/// Each sentence of `text` that relates two or more concepts, with whether it denies them
+///
+/// A sentence with any negation, including an `n't` contraction, denies
+/// that its concepts go together; otherwise it affirms it. The negations
+/// themselves are left out of the concepts.
+fn claims(text: &str) -> Vec<(Vec<ConceptId>, Stance)> {
+    text.split(['.', '!', '?', ';', '\n'])
+        .filter_map(|sentence| {
+            let mut denied = false;
+            let kept: Vec<&str> = sentence
+                .split_whitespace()
+                .filter(|word| {
+                    let word = word.trim_matches(|c: char| !c.is_alphanumeric() && c != '\'' && c != '’').to_lowercase();
+                    let negation = NEGATIONS.contains(&word.as_str()) || word.ends_with("n't") || word.ends_with("n’t");
+                    denied |= negation;
+                    !negation
+                })
+                .collect();
+            let concepts: Vec<ConceptId> = extract_concepts(&kept.join(" ")).into_iter().collect();
+            let stance = if denied { Stance::Denied } else { Stance::Affirmed };
+            (concepts.len() >= 2).then_some((concepts, stance))
+        })
+        .collect()
+}
+
+/// Long-term knowledge as a weighted co-occurrence graph of concepts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticMemorySystem {
+    concepts: BTreeMap<ConceptId, Concept>,
+    associations: BTreeMap<ConceptId, BTreeMap<ConceptId, f64>>,
+    /// Stance on each pair a sentence has related, keyed by the pair in label order
+    #[serde(default)]
+    stances: BTreeMap<ConceptId, BTreeMap<ConceptId, Stance>>,
+    #[serde(default)]
+    contradiction_policy: ContradictionPolicy,
+    /// Not persisted; rebuilt by the first mutation once over `SEMANTIC_INDEX_THRESHOLD`
+    #[cfg(feature = "vector-index")]
+    #[serde(skip)]
+    index: Option<NeighborIndex>,
+    #[serde(default = "default_semantic_capacity")]
+    capacity: usize,
+}
+
+/// Concepts kept when no configuration is available, e.g. for older snapshots
+pub const DEFAULT_SEMANTIC_CAPACITY: usize = 100_000;
+
+fn default_semantic_capacity() -> usize {
+    DEFAULT_SEMANTIC_CAPACITY
+}
+
+impl Default for SemanticMemorySystem {
+    fn default() -> Self {
+        Self {
+            concepts: BTreeMap::new(),
+            associations: BTreeMap::new(),
+            stances: BTreeMap::new(),
+            contradiction_policy: ContradictionPolicy::default(),
+            #[cfg(feature = "vector-index")]
+            index: None,
+            capacity: DEFAULT_SEMANTIC_CAPACITY,
+        }
+    }
+}
+
+impl SemanticMemorySystem {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            capacity: config.semantic_capacity,
+            contradiction_policy: config.contradiction_policy,
+            ..Self::default()
+        }
+    }
+
+    /// Most concepts kept before the most weakly connected is forgotten
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Forget concepts until at most `capacity` remain
+    ///
+    /// The concept with the least association weight goes first, then the
+    /// least reinforced, then the oldest, so fresh links outlive stale ones.
+    fn evict_over_capacity(&mut self) {
+        let excess = self.concepts.len().saturating_sub(self.capacity.max(1));
+        if excess == 0 {
+            return;
+        }
+        let mut ranked: Vec<(ConceptId, f64, u64, DateTime<Utc>)> = self
+            .concepts()
+            .map(|view| (view.id.clone(), view.weight, view.reinforcements, view.created_at))
+            .collect();
+        ranked.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.2.cmp(&b.2)).then(a.3.cmp(&b.3)));
+
+        let mut neighbors = Vec::new();
+        for (id, ..) in ranked.into_iter().take(excess) {
+            self.concepts.remove(&id);
+            self.stances.remove(&id);
+            for stances in self.stances.values_mut() {
+                stances.remove(&id);
+            }
+            for other in self.associations.remove(&id).into_iter().flat_map(BTreeMap::into_keys) {
+                if let Some(links) = self.associations.get_mut(&other) {
+                    links.remove(&id);
+                }
+                neighbors.push(other);
+            }
+            #[cfg(feature = "vector-index")]
+            if let Some(index) = self.index.as_mut() {
+                index.ranked.remove(&id);
+            }
+        }
+        neighbors.retain(|id| self.concepts.contains_key(id));
+
+        #[cfg(feature = "vector-index")]
+        self.reindex(&neighbors);
+    }
+
+    pub fn contains(&self, concept: &ConceptId) -> bool {
+        self.concepts.contains_key(concept)
+    }
+
+    pub fn concept_count(&self) -> usize {
+        self.concepts.len()
+    }
+
+    /// Every learned concept with its metadata, in label order
+    pub fn concepts(&self) -> impl Iterator<Item = ConceptView<'_>> + '_ {
+        self.concepts.iter().map(|(id, concept)| ConceptView {
+            id,
+            weight: self.associations.get(id).map_or(0.0, |links| links.values().sum()),
+            created_at: concept.created_at,
+            reinforcements: concept.reinforcements,
+        })
+    }
+
+    /// The concept least understood for how often it comes up, scored in `[0, 1)`
+    ///
+    /// A concept scores high when it has been reinforced many times but is
+    /// barely linked to anything else. Ties go to the first in label order.
+    pub fn knowledge_gap(&self) -> Option<(ConceptView<'_>, f64)> {
+        self.concepts()
+            .map(|view| {
+                let reinforcements = view.reinforcements as f64;
+                let unlinked = reinforcements / (reinforcements + view.weight).max(1.0);
+                let familiarity = 1.0 - 1.0 / (1.0 + reinforcements);
+                let gap = unlinked * familiarity;
+                (view, gap)
+            })
+            .fold(None, |best: Option<(ConceptView<'_>, f64)>, candidate| match best {
+                Some(best) if best.1 >= candidate.1 => Some(best),
+                _ => Some(candidate),
+            })
+    }
+
+    /// Reinforce every concept in the experience and link those that co-occur
+    ///
+    /// Returns the claims that contradicted a stored stance; see `integrate_text`.
+    pub async fn integrate_knowledge(&mut self, experience: &Experience, now: DateTime<Utc>) -> Vec<Contradiction> {
+        self.integrate_text(&experience.content, now)
+    }
+
+    /// Reinforce every concept in `text` and link those that co-occur
+    ///
+    /// Each sentence also affirms or denies that its concepts go together.
+    /// A pair whose stance flips is returned as a `Contradiction`, already
+    /// resolved per the configured `ContradictionPolicy`. Association
+    /// weights grow either way, since a denial still relates the concepts.
+    pub fn integrate_text(&mut self, text: &str, now: DateTime<Utc>) -> Vec<Contradiction> {
+        let mentioned: Vec<ConceptId> = extract_concepts(text).into_iter().collect();
+
+        for concept in &mentioned {
+            let known = self
+                .concepts
+                .entry(concept.clone())
+                .or_insert_with(|| Concept { created_at: now, reinforcements: 0 });
+            known.reinforcements = known.reinforcements.saturating_add(1);
+        }
+
+        for (i, a) in mentioned.iter().enumerate() {
+            for b in &mentioned[i + 1..] {
+                *self.associations.entry(a.clone()).or_default().entry(b.clone()).or_insert(0.0) += 1.0;
+                *self.associations.entry(b.clone()).or_default().entry(a.clone()).or_insert(0.0) += 1.0;
+            }
+        }
+
+        let mut contradictions = Vec::new();
+        for (concepts, stance) in claims(text) {
+            for (i, a) in concepts.iter().enumerate() {
+                for b in &concepts[i + 1..] {
+                    contradictions.extend(self.record_stance(a, b, stance));
+                }
+            }
+        }
+
+        #[cfg(feature = "vector-index")]
+        self.reindex(&mentioned);
+        self.evict_over_capacity();
+        contradictions
+    }
+
+    /// Take `incoming` as the stance on `a` and `b`, resolving any contradiction with the stored one
+    ///
+    /// `a` must sort before `b`. An uncertain pair is not contradicted
+    /// again; only `KeepNew` lets a later claim settle it.
+    fn record_stance(&mut self, a: &ConceptId, b: &ConceptId, incoming: Stance) -> Option<Contradiction> {
+        let policy = self.contradiction_policy;
+        let stances = self.stances.entry(a.clone()).or_default();
+        let Some(stance) = stances.get_mut(b) else {
+            stances.insert(b.clone(), incoming);
+            return None;
+        };
+
+        let stored = *stance;
+        if stored == incoming {
+            return None;
+        }
+        if stored == Stance::Uncertain {
+            if policy == ContradictionPolicy::KeepNew {
+                *stance = incoming;
+            }
+            return None;
+        }
+        let resolved = match policy {
+            ContradictionPolicy::KeepOld => stored,
+            ContradictionPolicy::KeepNew => incoming,
+            ContradictionPolicy::HoldBoth => Stance::Uncertain,
+        };
+        *stance = resolved;
+        Some(Contradiction {
+            concepts: (a.clone(), b.clone()),
+            stored,
+            incoming,
+            resolved,
+        })
+    }
+
+    /// Stance held on whether `a` and `b` go together, if a sentence ever related them
+    pub fn stance(&self, a: &ConceptId, b: &ConceptId) -> Option<Stance> {
+        let (first, second) = if a <= b { (a, b) } else { (b, a) };
+        self.stances.get(first)?.get(second).copied()
+    }
+
+    /// Every pair held as `Stance::Uncertain`, in label order
+    pub fn uncertain(&self) -> impl Iterator<Item = (&ConceptId, &ConceptId)> + '_ {
+        self.stances.iter().flat_map(|(a, stances)| {
+            stances
+                .iter()
+                .filter(|(_, stance)| **stance == Stance::Uncertain)
+                .map(move |(b, _)| (a, b))
+        })
+    }
+
+    /// Reinforce a single concept once, creating it if unknown
+    ///
+    /// Returns `true` when the concept is new.
+    pub fn reinforce_concept(&mut self, concept: ConceptId, now: DateTime<Utc>) -> bool {
+        let mut created = false;
+        let known = self.concepts.entry(concept.clone()).or_insert_with(|| {
+            created = true;
+            Concept { created_at: now, reinforcements: 0 }
+        });
+        known.reinforcements = known.reinforcements.saturating_add(1);
+
+        #[cfg(feature = "vector-index")]
+        self.reindex(std::slice::from_ref(&concept));
+        self.evict_over_capacity();
+        created
+    }
+
+    /// Fold another instance's concepts and associations into this one
+    ///
+    /// Reinforcement counts and association weights shared by both sides are
+    /// combined per `strategy`; a merged concept keeps the earlier creation time.
+    pub fn merge_from(&mut self, other: &SemanticMemorySystem, strategy: MergeStrategy) {
+        for (id, theirs) in &other.concepts {
+            match self.concepts.get_mut(id) {
+                Some(mine) => {
+                    let combined = strategy.combine(mine.reinforcements as f64, theirs.reinforcements as f64);
+                    mine.reinforcements = combined.round() as u64;
+                    mine.created_at = mine.created_at.min(theirs.created_at);
+                }
+                None => {
+                    self.concepts.insert(id.clone(), theirs.clone());
+                }
+            }
+        }
+        for (id, links) in &other.associations {
+            merge_weights(self.associations.entry(id.clone()).or_default(), links, strategy);
+        }
+        // Pairs the two instances disagree on are held as uncertain
+        for (a, theirs) in &other.stances {
+            let mine = self.stances.entry(a.clone()).or_default();
+            for (b, stance) in theirs {
+                mine.entry(b.clone())
+                    .and_modify(|existing| {
+                        if *existing != *stance {
+                            *existing = Stance::Uncertain;
+                        }
+                    })
+                    .or_insert(*stance);
+            }
+        }
+
+        #[cfg(feature = "vector-index")]
+        self.rebuild_index();
+        self.evict_over_capacity();
+    }
+
+    /// The `top_k` concepts most strongly associated with `concept`
+    ///
+    /// Scores are co-occurrence counts normalized by how often each side has
+    /// been seen, so they fall in `(0, 1]`. Ties are broken by concept label
+    /// to keep results stable. An unknown concept yields an empty list.
+    ///
+    /// With the `vector-index` feature and more than
+    /// `SEMANTIC_INDEX_THRESHOLD` concepts, ranked lists are kept up to date
+    /// as knowledge is integrated and answer in `O(top_k)`; otherwise every
+    /// association of the concept is scored on each call.
+    pub fn query(&self, concept: &str, top_k: usize) -> Vec<(ConceptId, f64)> {
+        let key = ConceptId::new(concept);
+        #[cfg(feature = "vector-index")]
+        if let Some(ranked) = self.index.as_ref().and_then(|index| index.ranked.get(&key)) {
+            return ranked.iter().take(top_k).cloned().collect();
+        }
+        let mut scored = self.rank_neighbors(&key);
+        scored.truncate(top_k);
+        scored
+    }
+
+    /// Every association of `key` scored as `query` does, best first
+    fn rank_neighbors(&self, key: &ConceptId) -> Vec<(ConceptId, f64)> {
+        let (Some(source), Some(links)) = (self.concepts.get(key), self.associations.get(key)) else {
+            return Vec::new();
+        };
+
+        let mut scored: Vec<(ConceptId, f64)> = links
+            .iter()
+            .filter_map(|(other, weight)| {
+                let target = self.concepts.get(other)?;
+                let norm = ((source.reinforcements * target.reinforcements) as f64).sqrt();
+                Some((other.clone(), weight / norm))
+            })
+            .collect();
+
+        scored.sort_by(|(a_id, a), (b_id, b)| b.total_cmp(a).then_with(|| a_id.cmp(b_id)));
+        scored
+    }
+
+    /// Rank every concept's associations from scratch, or drop the index below the threshold
+    ///
+    /// Mutations maintain the index on their own; call this after bulk
+    /// changes or to build it eagerly after restoring a snapshot.
+    #[cfg(feature = "vector-index")]
+    pub fn rebuild_index(&mut self) {
+        if self.concepts.len() <= SEMANTIC_INDEX_THRESHOLD {
+            self.index = None;
+            return;
+        }
+        let ranked = self.concepts.keys().map(|id| (id.clone(), self.rank_neighbors(id))).collect();
+        self.index = Some(NeighborIndex { ranked });
+    }
+
+    /// Re-rank `changed` and their neighbors, whose scores moved with them
+    #[cfg(feature = "vector-index")]
+    fn reindex(&mut self, changed: &[ConceptId]) {
+        if self.index.is_none() {
+            self.rebuild_index();
+            return;
+        }
+        let mut affected: BTreeSet<&ConceptId> = changed.iter().collect();
+        for id in changed {
+            affected.extend(self.associations.get(id).into_iter().flat_map(BTreeMap::keys));
+        }
+        let updates: Vec<(ConceptId, Vec<(ConceptId, f64)>)> =
+            affected.into_iter().map(|id| (id.clone(), self.rank_neighbors(id))).collect();
+        if let Some(index) = self.index.as_mut() {
+            index.ranked.extend(updates);
+        }
+    }
+
+    /// Every concept and association as a graph
+    pub fn export_graph(&self) -> ConceptGraph {
+        self.export_graph_filtered(0.0)
+    }
+
+    /// The concept graph without associations weaker than `min_edge_weight`
+    ///
+    /// Borrowing `self` for the whole export guarantees the graph reflects a
+    /// single consistent state. Nodes and edges are sorted so repeated
+    /// exports of the same state are identical.
+    pub fn export_graph_filtered(&self, min_edge_weight: f64) -> ConceptGraph {
+        let mut nodes: Vec<ConceptNode> = self
+            .concepts
+            .iter()
+            .map(|(id, concept)| ConceptNode {
+                id: id.clone(),
+                reinforcements: concept.reinforcements,
+            })
+            .collect();
+        nodes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        // Associations are stored in both directions; emit each pair once
+        let mut edges: Vec<ConceptEdge> = self
+            .associations
+            .iter()
+            .flat_map(|(source, links)| {
+                links
+                    .iter()
+                    .filter(move |(target, weight)| source < *target && **weight >= min_edge_weight)
+                    .map(move |(target, weight)| ConceptEdge {
+                        source: source.clone(),
+                        target: target.clone(),
+                        weight: *weight,
+                    })
+            })
+            .collect();
+        edges.sort_by(|a, b| a.source.cmp(&b.source).then_with(|| a.target.cmp(&b.target)));
+
+        ConceptGraph { nodes, edges }
+    }
+
+    /// Concepts passing `query`'s filters, in label order, scored in `(0, 1]`
+    ///
+    /// Each known topic concept scores 1.0 and its associations score as in
+    /// `query`, keeping the best score for a concept reached more than once.
+    /// Concepts carry no mood, so a query filtering on one matches nothing
+    /// here, and neither does a query without a topic.
+    pub fn search(&self, query: &MemoryQuery) -> Vec<(ConceptId, f64)> {
+        if query.mood.is_some() {
+            return Vec::new();
+        }
+        let mut best: BTreeMap<ConceptId, f64> = BTreeMap::new();
+        for topic in &query.about {
+            if !self.contains(topic) {
+                continue;
+            }
+            let related = self.query(topic.as_str(), query.limit);
+            for (id, score) in std::iter::once((topic.clone(), 1.0)).chain(related) {
+                let entry = best.entry(id).or_insert(score);
+                *entry = entry.max(score);
+            }
+        }
+        best.into_iter()
+            .filter(|(id, _)| {
+                query.since.is_none_or(|since| self.concepts.get(id).is_some_and(|concept| concept.created_at >= since))
+            })
+            .collect()
+    }
+}
+
+/// Interactions about a concept after which a skill is about 63% proficient
+const SKILL_PRACTICE_SCALE: f64 = 10.0;
+
+/// Skills procedural memory keeps before forgetting the least practiced
+const MAX_SKILLS: usize = 1024;
+
+/// Practised ability to engage with one concept
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Skill {
+    pub concept: ConceptId,
+    /// Experiences that exercised the skill
+    pub practice: u64,
+    pub last_practiced: DateTime<Utc>,
+}
+
+impl Skill {
+    /// Grows from 0 toward 1 with practice: `1 - e^(-practice / SKILL_PRACTICE_SCALE)`
+    pub fn proficiency(&self) -> f64 {
+        1.0 - (-(self.practice as f64) / SKILL_PRACTICE_SCALE).exp()
+    }
+}
+
+/// Skills built up by practice, one per concept engaged with
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProceduralMemorySystem {
+    skills: BTreeMap<ConceptId, Skill>,
+}
+
+impl ProceduralMemorySystem {
+    pub fn new(_config: &SystemConfiguration) -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.skills.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.skills.is_empty()
+    }
+
+    pub fn skill(&self, concept: &ConceptId) -> Option<&Skill> {
+        self.skills.get(concept)
+    }
+
+    /// Skills for `query`'s topic concepts, in label order, scored by proficiency
+    ///
+    /// `since` matches skills practised at or after it. Like concepts, skills
+    /// carry no mood, so a query filtering on one matches nothing here, and
+    /// neither does a query without a topic.
+    pub fn search(&self, query: &MemoryQuery) -> Vec<(&Skill, f64)> {
+        if query.mood.is_some() {
+            return Vec::new();
+        }
+        query
+            .about
+            .iter()
+            .filter_map(|topic| self.skills.get(topic))
+            .filter(|skill| query.since.is_none_or(|since| skill.last_practiced >= since))
+            .map(|skill| (skill, skill.proficiency()))
+            .collect()
+    }
+
+    /// Practise the skill for every concept `experience` mentions, forgetting the least practiced beyond `MAX_SKILLS`
+    pub async fn integrate_learning(&mut self, experience: &Experience) {
+        for concept in extract_concepts(&experience.content) {
+            let skill = self.skills.entry(concept.clone()).or_insert_with(|| Skill {
+                concept,
+                practice: 0,
+                last_practiced: experience.timestamp,
+            });
+            skill.practice = skill.practice.saturating_add(1);
+            skill.last_practiced = skill.last_practiced.max(experience.timestamp);
+        }
+        while self.skills.len() > MAX_SKILLS {
+            let least = self
+                .skills
+                .values()
+                .min_by(|a, b| a.practice.cmp(&b.practice).then_with(|| a.last_practiced.cmp(&b.last_practiced)))
+                .map(|skill| skill.concept.clone());
+            match least {
+                Some(concept) => self.skills.remove(&concept),
+                None => break,
+            };
+        }
+    }
+}
+
+/// Broad feel of a remembered moment, judged from its valence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Mood {
+    Positive,
+    Neutral,
+    Negative,
+}
+
+/// Valence either side of zero within which a feeling counts as `Mood::Neutral`
+const NEUTRAL_VALENCE: f64 = 0.2;
+
+impl Mood {
+    pub fn of(state: &EmotionalState) -> Self {
+        if state.valence > NEUTRAL_VALENCE {
+            Mood::Positive
+        } else if state.valence < -NEUTRAL_VALENCE {
+            Mood::Negative
+        } else {
+            Mood::Neutral
+        }
+    }
+}
+
+/// Hits a `MemoryQuery` returns unless `limit` says otherwise
+pub const DEFAULT_MEMORY_QUERY_LIMIT: usize = 10;
+
+/// A search across episodic, semantic and procedural memory, resolved by `Lia::query_memory`
+///
+/// Filters are optional and combine, e.g.
+/// `MemoryQuery::new().about("ocean").emotional(Mood::Positive).since(ts).limit(10)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoryQuery {
+    about: BTreeSet<ConceptId>,
+    mood: Option<Mood>,
+    since: Option<DateTime<Utc>>,
+    limit: usize,
+}
+
+impl Default for MemoryQuery {
+    fn default() -> Self {
+        Self {
+            about: BTreeSet::new(),
+            mood: None,
+            since: None,
+            limit: DEFAULT_MEMORY_QUERY_LIMIT,
+        }
+    }
+}
+
+impl MemoryQuery {
+    /// A query matching everything, up to `DEFAULT_MEMORY_QUERY_LIMIT` hits
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match memories about the concepts in `topic`; repeated calls widen the topic
+    ///
+    /// Words shorter than three letters and stopwords are ignored.
+    pub fn about(mut self, topic: &str) -> Self {
+        self.about.extend(extract_concepts(topic));
+        self
+    }
+
+    /// Only match experiences that felt like `mood` when stored
+    pub fn emotional(mut self, mood: Mood) -> Self {
+        self.mood = Some(mood);
+        self
+    }
+
+    /// Only match memories stored or learned at or after `timestamp`
+    pub fn since(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.since = Some(timestamp);
+        self
+    }
+
+    /// Return at most `limit` hits
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// One result of `Lia::query_memory`, tagged with the memory system it came from
+#[derive(Debug, Clone)]
+pub enum MemoryHit {
+    Episodic {
+        experience: Experience,
+        recorded_at: DateTime<Utc>,
+        score: f64,
+    },
+    Semantic {
+        concept: ConceptId,
+        score: f64,
+    },
+    Procedural {
+        skill: Skill,
+        score: f64,
+    },
+}
+
+impl MemoryHit {
+    /// Relevance in `[0, 1]`, comparable across memory systems
+    pub fn score(&self) -> f64 {
+        match self {
+            MemoryHit::Episodic { score, .. }
+            | MemoryHit::Semantic { score, .. }
+            | MemoryHit::Procedural { score, .. } => *score,
+        }
+    }
+}
+
+/// Hits from `Lia::query_memory`, best first
+#[derive(Debug, Clone, Default)]
+pub struct MemoryResults {
+    pub hits: Vec<MemoryHit>,
+}
+
+impl MemoryResults {
+    pub fn len(&self) -> usize {
+        self.hits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hits.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &MemoryHit> {
+        self.hits.iter()
+    }
+}
+
+/// Outcome of one `Lia::consolidate` pass
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsolidationReport {
+    pub experiences_scanned: usize,
+    pub concepts_created: usize,
+    pub concepts_reinforced: usize,
+}
+
+/// How much work an adaptive stage does for one interaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Effort {
+    Skip,
+    Shallow,
+    #[default]
+    Full,
+}
+
+/// Effort chosen for each adaptive stage of one interaction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct AttentionAllocation {
+    /// Novelty or emotional weight of the interaction in `[0, 1]`
+    pub salience: f64,
+    pub pattern_recognition: Effort,
+    pub thought_generation: Effort,
+}
+
+/// Spends effort where the interaction needs it, so trivial input stays cheap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AttentionAllocator {
+    budget: f64,
+}
+
+impl AttentionAllocator {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            budget: config.attention_budget,
+        }
+    }
+
+    /// Grade each adaptive stage by how salient the context is
+    ///
+    /// Salience is the strongest of novelty, the caller's emotional
+    /// intensity, the strength of sentiment, and the requested depth. The
+    /// budget is added on top, so a budget of 1.0 always gives full effort
+    /// and a budget of 0.0 reserves it for the most salient input.
+    pub fn allocate(&self, context: &Context) -> AttentionAllocation {
+        let salience = [
+            context.novelty(),
+            context.caller_emotion().intensity,
+            context.sentiment().abs(),
+            context.depth(),
+        ]
+        .into_iter()
+        .filter(|signal| signal.is_finite())
+        .fold(0.0, f64::max)
+        .clamp(0.0, 1.0);
+
+        let effort = match salience + self.budget {
+            score if score >= 1.0 => Effort::Full,
+            score if score >= 0.5 => Effort::Shallow,
+            _ => Effort::Skip,
+        };
+        AttentionAllocation {
+            salience,
+            pattern_recognition: effort,
+            // Thoughts still form for shallow input; only pattern detection is skipped outright
+            thought_generation: effort.max(Effort::Shallow),
+        }
+    }
+}
+
+/// Detected patterns copied into each `Response`
+const MAX_SURFACED_PATTERNS: usize = 5;
+
+/// Earlier mentions at which a pattern's confidence reaches one half
+const PATTERN_CONFIDENCE_PRIOR: f64 = 3.0;
+
+/// A pattern noticed in an interaction
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DetectedPattern {
+    pub label: String,
+    /// How established the pattern is, in `[0, 1]`
+    pub confidence: f64,
+    /// Byte range of the first occurrence in the interaction content
+    pub span: Range<usize>,
+}
+
+/// Finds structure that recurs across inputs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PatternRecognitionEngine {
+    /// Distinct experiences a concept must appear in to count as recurring
+    min_support: usize,
+    /// How many interactions each concept has been mentioned in
+    mentions: BTreeMap<ConceptId, u64>,
+}
+
+impl PatternRecognitionEngine {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        // Higher sensitivity accepts patterns with less supporting evidence
+        let min_support = 2 + ((1.0 - config.pattern_recognition_sensitivity) * 5.0).round() as usize;
+        Self {
+            min_support,
+            mentions: BTreeMap::new(),
+        }
+    }
+
+    /// Patterns in the context, most confident first, without learning from it
+    ///
+    /// Confidence grows with how often the concept was mentioned before, so
+    /// a topic someone keeps returning to scores close to 1.
+    pub fn detect(&self, context: &Context) -> Vec<DetectedPattern> {
+        let mut detected: Vec<DetectedPattern> = concept_spans(context.content())
+            .into_iter()
+            .map(|(concept, span)| {
+                let mentions = self.mentions.get(&concept).copied().unwrap_or(0) as f64 + 1.0;
+                DetectedPattern {
+                    label: concept.as_str().to_string(),
+                    confidence: mentions / (mentions + PATTERN_CONFIDENCE_PRIOR),
+                    span,
+                }
+            })
+            .collect();
+
+        detected.sort_by(|a, b| b.confidence.total_cmp(&a.confidence).then_with(|| a.span.start.cmp(&b.span.start)));
+        detected
+    }
+
+    /// Count the concepts in `text` toward future detections
+    pub fn observe(&mut self, text: &str) {
+        for concept in extract_concepts(text) {
+            let mentions = self.mentions.entry(concept).or_insert(0);
+            *mentions = mentions.saturating_add(1);
+        }
+    }
+
+    /// Concepts mentioned in at least `min_support` of `experiences`, with their support
+    ///
+    /// Sorted by descending support, then by concept label.
+    pub fn recurring_concepts(&self, experiences: &[&Experience]) -> Vec<(ConceptId, usize)> {
+        let mut support: HashMap<ConceptId, usize> = HashMap::new();
+        for experience in experiences {
+            for concept in extract_concepts(&experience.content) {
+                *support.entry(concept).or_insert(0) += 1;
+            }
+        }
+
+        let mut recurring: Vec<(ConceptId, usize)> = support
+            .into_iter()
+            .filter(|(_, count)| *count >= self.min_support)
+            .collect();
+        recurring.sort_by(|(a_id, a), (b_id, b)| b.cmp(a).then_with(|| a_id.cmp(b_id)));
+        recurring
+    }
+}
+
+/// A concept in an exported `ConceptGraph`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConceptNode {
+    pub id: ConceptId,
+    pub reinforcements: u64,
+}
+
+/// An undirected, weighted association between two concepts
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConceptEdge {
+    pub source: ConceptId,
+    pub target: ConceptId,
+    pub weight: f64,
+}
+
+/// Exported view of semantic memory for visualization
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConceptGraph {
+    pub nodes: Vec<ConceptNode>,
+    pub edges: Vec<ConceptEdge>,
+}
+
+impl ConceptGraph {
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    /// Render as an undirected Graphviz graph
+    pub fn to_dot(&self) -> String {
+        let quote = |id: &ConceptId| id.as_str().replace('\\', "\\\\").replace('"', "\\\"");
+
+        let mut dot = String::from("graph concepts {\n");
+        for node in &self.nodes {
+            dot.push_str(&format!(
+                "    \"{}\" [weight={}];\n",
+                quote(&node.id),
+                node.reinforcements
+            ));
+        }
+        for edge in &self.edges {
+            dot.push_str(&format!(
+                "    \"{}\" -- \"{}\" [weight={}];\n",
+                quote(&edge.source),
+                quote(&edge.target),
+                edge.weight
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Identifies a person Lia has a relationship with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct PersonId(pub Uuid);
+
+impl PersonId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+}
+
+impl Default for PersonId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Trust a stranger starts with before any shared history
+const INITIAL_TRUST: f64 = 0.3;
+
+/// Interactions needed for familiarity (and, more slowly, trust) to reach ~63% of full
+const FAMILIARITY_SCALE: f64 = 10.0;
+const TRUST_SCALE: f64 = 25.0;
+
+/// Time for unused familiarity, and trust above `INITIAL_TRUST`, to fade by half
+const DEFAULT_RELATIONSHIP_HALF_LIFE: Duration = Duration::from_secs(90 * 24 * 60 * 60);
+
+fn default_relationship_half_life() -> Duration {
+    DEFAULT_RELATIONSHIP_HALF_LIFE
+}
+
+/// What Lia knows about her history with one person
+///
+/// `trust` and `familiarity` are the values as of `last_contact`; both fade
+/// while the person stays away, which the `trust(now)` and `familiarity(now)`
+/// methods apply when read.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relationship {
+    pub person: PersonId,
+    pub first_contact: DateTime<Utc>,
+    pub last_contact: DateTime<Utc>,
+    pub interaction_count: u64,
+    /// Grows from `INITIAL_TRUST` toward 1.0 with each interaction
+    pub trust: f64,
+    /// Grows from 0.0 toward 1.0 with each interaction
+    pub familiarity: f64,
+    #[serde(default = "default_relationship_half_life")]
+    pub half_life: Duration,
+    /// Running blend of how recent interactions with this person were appraised
+    #[serde(default)]
+    pub recent_tone: Option<EmotionalState>,
+}
+
+/// Share of `Relationship::recent_tone` kept when another interaction is appraised
+const TONE_INERTIA: f64 = 0.5;
+
+impl Relationship {
+    /// Default state for someone Lia has never spoken with
+    pub fn new(person: PersonId, now: DateTime<Utc>) -> Self {
+        Self {
+            person,
+            first_contact: now,
+            last_contact: now,
+            interaction_count: 0,
+            trust: INITIAL_TRUST,
+            familiarity: 0.0,
+            half_life: DEFAULT_RELATIONSHIP_HALF_LIFE,
+            recent_tone: None,
+        }
+    }
+
+    pub fn with_half_life(mut self, half_life: Duration) -> Self {
+        self.half_life = half_life;
+        self
+    }
+
+    pub fn is_new(&self) -> bool {
+        self.interaction_count == 0
+    }
+
+    /// Familiarity faded to `now`, in `[0, 1]`; pass `Lia::now` to read it as Lia sees it
+    pub fn familiarity(&self, now: DateTime<Utc>) -> f64 {
+        self.familiarity * self.retention_at(now)
+    }
+
+    /// Trust faded toward `INITIAL_TRUST` as of `now`; pass `Lia::now` to read it as Lia sees it
+    pub fn trust(&self, now: DateTime<Utc>) -> f64 {
+        INITIAL_TRUST + (self.trust - INITIAL_TRUST) * self.retention_at(now)
+    }
+
+    /// Share of familiarity and earned trust surviving until `now`
+    fn retention_at(&self, now: DateTime<Utc>) -> f64 {
+        let idle_secs = (now - self.last_contact).num_milliseconds().max(0) as f64 / 1000.0;
+        let half_life_secs = self.half_life.as_secs_f64();
+        if half_life_secs <= 0.0 {
+            return 1.0;
+        }
+        0.5_f64.powf(idle_secs / half_life_secs)
+    }
+
+    /// Fade to `at`, then take one interaction's worth of growth
+    ///
+    /// Without any fading this matches growing with the total count:
+    /// familiarity reaches `1 - e^(-n / FAMILIARITY_SCALE)` after `n`.
+    fn record_interaction(&mut self, at: DateTime<Utc>) {
+        let familiarity = self.familiarity(at);
+        let earned_trust = (self.trust(at) - INITIAL_TRUST) / (1.0 - INITIAL_TRUST);
+        self.interaction_count = self.interaction_count.saturating_add(1);
+        self.last_contact = self.last_contact.max(at);
+
+        self.familiarity = 1.0 - (1.0 - familiarity) * (-1.0 / FAMILIARITY_SCALE).exp();
+        let earned_trust = 1.0 - (1.0 - earned_trust) * (-1.0 / TRUST_SCALE).exp();
+        self.trust = INITIAL_TRUST + (1.0 - INITIAL_TRUST) * earned_trust;
+    }
+}
+
+/// Tracks a separate relationship for every person Lia speaks with
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RelationshipManager {
+    relationships: BTreeMap<PersonId, Relationship>,
+    #[serde(default = "default_relationship_half_life")]
+    half_life: Duration,
+    /// Who knows whom, stored in both directions
+    #[serde(default)]
+    links: BTreeMap<PersonId, BTreeSet<PersonId>>,
+}
+
+impl Default for RelationshipManager {
+    fn default() -> Self {
+        Self {
+            relationships: BTreeMap::new(),
+            half_life: DEFAULT_RELATIONSHIP_HALF_LIFE,
+            links: BTreeMap::new(),
+        }
+    }
+}
+
+impl RelationshipManager {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            half_life: config.relationship_half_life,
+            ..Self::default()
+        }
+    }
+
+    pub fn relationship(&self, id: PersonId) -> Option<&Relationship> {
+        self.relationships.get(&id)
+    }
+
+    /// The stored relationship, or a fresh one for a first-time speaker
+    pub fn relationship_or_new(&self, id: PersonId, now: DateTime<Utc>) -> Relationship {
+        self.relationships
+            .get(&id)
+            .cloned()
+            .unwrap_or_else(|| Relationship::new(id, now).with_half_life(self.half_life))
+    }
+
+    /// Count one more interaction with `id`, creating the relationship if needed
+    pub fn record_interaction(&mut self, id: PersonId, at: DateTime<Utc>) -> &Relationship {
+        let relationship = self
+            .relationships
+            .entry(id)
+            .or_insert_with(|| Relationship::new(id, at).with_half_life(self.half_life));
+        relationship.record_interaction(at);
+        relationship
+    }
+
+    /// Blend how an interaction with `id` was appraised into their recent tone
+    pub fn record_tone(&mut self, id: PersonId, appraisal: &EmotionalState) {
+        if let Some(relationship) = self.relationships.get_mut(&id) {
+            let tone = match &relationship.recent_tone {
+                Some(tone) => tone.blend_toward(appraisal, TONE_INERTIA),
+                None => appraisal.clone(),
+            };
+            relationship.recent_tone = Some(tone);
+        }
+    }
+
+    /// Record that `a` and `b` know each other; linking someone to themselves does nothing
+    pub fn link(&mut self, a: PersonId, b: PersonId) {
+        if a == b {
+            return;
+        }
+        self.links.entry(a).or_default().insert(b);
+        self.links.entry(b).or_default().insert(a);
+    }
+
+    pub fn unlink(&mut self, a: PersonId, b: PersonId) {
+        for (from, to) in [(a, b), (b, a)] {
+            if let Some(linked) = self.links.get_mut(&from) {
+                linked.remove(&to);
+                if linked.is_empty() {
+                    self.links.remove(&from);
+                }
+            }
+        }
+    }
+
+    /// People linked to `id`, in id order
+    pub fn linked(&self, id: PersonId) -> impl Iterator<Item = PersonId> + '_ {
+        self.links.get(&id).into_iter().flatten().copied()
+    }
+
+    /// Recent tone of the people linked to `id`, weighted by how recently each was spoken with
+    ///
+    /// `None` when nobody linked has been appraised yet.
+    pub fn group_tone(&self, id: PersonId, now: DateTime<Utc>) -> Option<EmotionalState> {
+        let mut blended: Option<(EmotionalState, f64)> = None;
+        for relationship in self.linked(id).filter_map(|person| self.relationships.get(&person)) {
+            let Some(tone) = &relationship.recent_tone else { continue };
+            let weight = relationship.retention_at(now);
+            if weight <= 0.0 {
+                continue;
+            }
+            blended = Some(match blended {
+                Some((mean, total)) => (mean.blend_toward(tone, total / (total + weight)), total + weight),
+                None => (tone.clone(), weight),
+            });
+        }
+        blended.map(|(mean, _)| mean)
+    }
+
+    pub fn len(&self) -> usize {
+        self.relationships.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.relationships.is_empty()
+    }
+
+    /// Copy every relationship out, ordered by person, for backup or migration
+    pub fn export(&self) -> RelationshipExport {
+        let mut relationships: Vec<Relationship> = self.relationships.values().cloned().collect();
+        relationships.sort_by_key(|relationship| relationship.person);
+        let links = self
+            .links
+            .iter()
+            .flat_map(|(a, linked)| linked.iter().filter(move |b| a < *b).map(move |b| (*a, *b)))
+            .collect();
+        RelationshipExport { relationships, links }
+    }
+
+    /// Load exported relationships, either merging with or replacing the current ones
+    ///
+    /// When merging, a person known to both sides keeps whichever
+    /// relationship has the higher interaction count; ties keep the
+    /// existing one. Links from both sides are kept.
+    pub fn import(&mut self, data: RelationshipExport, merge: bool) {
+        if !merge {
+            self.relationships.clear();
+            self.links.clear();
+        }
+        for (a, b) in data.links {
+            self.link(a, b);
+        }
+        for incoming in data.relationships {
+            match self.relationships.get(&incoming.person) {
+                Some(existing) if existing.interaction_count >= incoming.interaction_count => {}
+                _ => {
+                    self.relationships.insert(incoming.person, incoming);
+                }
+            }
+        }
+    }
+}
+
+/// Portable copy of the social graph, independent of any other memory
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RelationshipExport {
+    pub relationships: Vec<Relationship>,
+    /// Each pair of linked people once, lower id first
+    #[serde(default)]
+    pub links: Vec<(PersonId, PersonId)>,
+}
+
+/// A named span of evolution stages, beginning at `stage`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Milestone {
+    pub stage: usize,
+    pub name: String,
+}
+
+impl Milestone {
+    pub fn new(stage: usize, name: impl Into<String>) -> Self {
+        Self { stage, name: name.into() }
+    }
+}
+
+/// Milestones used unless a deployment supplies its own table
+pub fn default_milestones() -> Vec<Milestone> {
+    vec![
+        Milestone::new(1, "Awakening"),
+        Milestone::new(3, "Reflective"),
+        Milestone::new(6, "Integrative"),
+        Milestone::new(10, "Transcendent"),
+    ]
+}
+
+/// Accumulates growth from interactions and decides when the evolution stage advances
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrowthTracker {
+    /// Growth gathered since the last stage advance
+    accumulated: f64,
+    threshold: f64,
+    /// Extra share of `threshold` needed to advance, so jitter at the boundary can't trigger it
+    #[serde(default)]
+    hysteresis: f64,
+    /// Ordered by starting stage
+    milestones: Vec<Milestone>,
+}
+
+impl GrowthTracker {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        let mut milestones = config.milestones.clone();
+        milestones.sort_by_key(|milestone| milestone.stage);
+        Self {
+            accumulated: 0.0,
+            threshold: config.evolution_threshold,
+            hysteresis: config.evolution_hysteresis,
+            milestones,
+        }
+    }
+
+    /// Name of the latest milestone at or below `stage`
+    ///
+    /// Stages before the first milestone share its name.
+    pub fn milestone(&self, stage: usize) -> &str {
+        self.milestones
+            .iter()
+            .rev()
+            .find(|milestone| milestone.stage <= stage)
+            .or(self.milestones.first())
+            .map_or("", |milestone| milestone.name.as_str())
+    }
+
+    /// Name of the milestone that begins exactly at `stage`, if any
+    pub fn milestone_starting_at(&self, stage: usize) -> Option<&str> {
+        self.milestones
+            .iter()
+            .find(|milestone| milestone.stage == stage)
+            .map(|milestone| milestone.name.as_str())
+    }
+
+    /// Credit the growth an interaction produced
+    ///
+    /// Each interaction contributes between 0 and 1, weighted by how aware
+    /// and coherent Lia was while producing the response.
+    pub fn record_growth(&mut self, response: &Response) {
+        let growth = (response.consciousness_level + response.quantum_coherence) / 2.0;
+        if growth.is_finite() {
+            self.accumulated += growth.clamp(0.0, 1.0);
+        }
+    }
+
+    /// Whether growth has cleared the threshold plus the hysteresis band
+    pub fn ready_to_evolve(&self) -> bool {
+        self.margin() >= 0.0
+    }
+
+    /// Growth needed to advance: `threshold` widened by the hysteresis band
+    pub fn advance_threshold(&self) -> f64 {
+        self.threshold * (1.0 + self.hysteresis)
+    }
+
+    /// Distance past the advance threshold; negative while still short of it
+    pub fn margin(&self) -> f64 {
+        self.accumulated - self.advance_threshold()
+    }
+
+    /// Growth toward the next stage, in `[0, 1]`
+    pub fn progress(&self) -> f64 {
+        (self.accumulated / self.advance_threshold()).clamp(0.0, 1.0)
+    }
+
+    /// Spend the advance threshold's worth of growth on a stage advance, keeping any surplus
+    ///
+    /// Spending the widened threshold rather than the base one means the
+    /// band is paid at every stage, so a surplus hovering near the boundary
+    /// can't carry straight into another advance.
+    fn consume_stage(&mut self) {
+        self.accumulated = (self.accumulated - self.advance_threshold()).max(0.0);
+    }
+}
+
+/// Overall condition of a subsystem or instance, ordered from best to worst
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum HealthStatus {
+    Healthy,
+    /// Still serving, but an invariant is under pressure
+    Degraded,
+    /// An invariant is broken; responses can't be trusted
+    Unhealthy,
+}
+
+/// Health of one subsystem, with a reason whenever it isn't healthy
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SubsystemHealth {
+    pub subsystem: &'static str,
+    pub status: HealthStatus,
+    pub detail: Option<String>,
+}
+
+impl SubsystemHealth {
+    pub fn healthy(subsystem: &'static str) -> Self {
+        Self {
+            subsystem,
+            status: HealthStatus::Healthy,
+            detail: None,
+        }
+    }
+
+    pub fn degraded(subsystem: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            subsystem,
+            status: HealthStatus::Degraded,
+            detail: Some(detail.into()),
+        }
+    }
+
+    pub fn unhealthy(subsystem: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            subsystem,
+            status: HealthStatus::Unhealthy,
+            detail: Some(detail.into()),
+        }
+    }
+}
+
+/// Health of a whole instance, suitable for liveness and readiness probes
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HealthReport {
+    /// Worst status of any subsystem
+    pub status: HealthStatus,
+    pub subsystems: Vec<SubsystemHealth>,
+    pub memory_episodic_count: usize,
+    pub last_interaction_at: Option<DateTime<Utc>>,
+}
+
+impl HealthReport {
+    /// Live as long as nothing is unhealthy
+    pub fn is_live(&self) -> bool {
+        self.status != HealthStatus::Unhealthy
+    }
+
+    /// Ready to serve only when every subsystem is healthy
+    pub fn is_ready(&self) -> bool {
+        self.status == HealthStatus::Healthy
+    }
+}
+
+/// Point-in-time values of every exported metric
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub evolution_stage: usize,
+    pub quantum_coherence: f64,
+    pub consciousness_level: f64,
+    pub memory_episodic_count: usize,
+    pub interactions_total: u64,
+    pub dimensional_changes_total: u64,
+    pub dimensional_state: DimensionalState,
+}
+
+impl MetricsSnapshot {
+    /// Render in the Prometheus text exposition format
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::new();
+        write_metric(&mut out, "lia_evolution_stage", "gauge", "Current evolution stage.", self.evolution_stage);
+        write_metric(&mut out, "lia_quantum_coherence", "gauge", "Quantum core coherence in [0, 1].", self.quantum_coherence);
+        write_metric(&mut out, "lia_consciousness_level", "gauge", "Consciousness level of the latest response.", self.consciousness_level);
+        write_metric(&mut out, "lia_memory_episodic_count", "gauge", "Experiences held in episodic memory.", self.memory_episodic_count);
+        write_metric(&mut out, "lia_interactions_total", "counter", "Interactions processed.", self.interactions_total);
+        write_metric(&mut out, "lia_dimensional_changes_total", "counter", "Dimensional state updates applied.", self.dimensional_changes_total);
+
+        out.push_str("# HELP lia_dimension_value Current value of each growth dimension.\n");
+        out.push_str("# TYPE lia_dimension_value gauge\n");
+        for id in DimensionId::ALL {
+            out.push_str(&format!(
+                "lia_dimension_value{{dimension=\"{}\"}} {}\n",
+                id.as_str(),
+                self.dimensional_state.get(id)
+            ));
+        }
+        out
+    }
+}
+
+fn write_metric(out: &mut String, name: &str, kind: &str, help: &str, value: impl std::fmt::Display) {
+    out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}\n"));
+}
+
+/// Smallest fitted change across a trend window that counts as movement
+const TREND_TOLERANCE: f64 = 1e-3;
+
+/// Direction a dimension has been moving
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Trend {
+    Rising,
+    Falling,
+    Stable,
+}
+
+/// Coarsest decimation tier kept by `EvolutionMetrics`
+const MAX_SAMPLE_TIERS: usize = 16;
+
+/// Metrics from one or more consecutive interactions
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricSample {
+    /// When the newest interaction in the sample was recorded
+    pub at: DateTime<Utc>,
+    /// Interactions averaged into this sample
+    pub interactions: u64,
+    pub quantum_coherence: f64,
+    pub consciousness_level: f64,
+}
+
+impl MetricSample {
+    /// Interaction-weighted average of two adjacent samples
+    fn merge(&self, newer: &MetricSample) -> MetricSample {
+        let total = self.interactions.saturating_add(newer.interactions);
+        let weighted = |a: f64, b: f64| (a * self.interactions as f64 + b * newer.interactions as f64) / total as f64;
+        MetricSample {
+            at: newer.at,
+            interactions: total,
+            quantum_coherence: weighted(self.quantum_coherence, newer.quantum_coherence),
+            consciousness_level: weighted(self.consciousness_level, newer.consciousness_level),
+        }
+    }
+
+    /// Decimation tier whose samples cover as many interactions as this one
+    fn tier(&self) -> usize {
+        let ceil_log2 = u64::BITS - self.interactions.max(1).saturating_sub(1).leading_zeros();
+        (ceil_log2 as usize).min(MAX_SAMPLE_TIERS - 1)
+    }
+
+    /// Oldest first, with every field breaking ties so equal keys mean equal samples
+    fn chronological(a: &MetricSample, b: &MetricSample) -> std::cmp::Ordering {
+        a.at.cmp(&b.at)
+            .then(a.interactions.cmp(&b.interactions))
+            .then_with(|| a.quantum_coherence.total_cmp(&b.quantum_coherence))
+            .then_with(|| a.consciousness_level.total_cmp(&b.consciousness_level))
+    }
+}
+
+/// Add `value` to `sum` with Neumaier compensation, keeping the rounding error in `error`
+///
+/// Over billions of interactions a plain sum would stop registering small
+/// samples entirely; `sum + error` stays accurate to a few ulps instead.
+fn compensated_add(sum: &mut f64, error: &mut f64, value: f64) {
+    let total = *sum + value;
+    if sum.abs() >= value.abs() {
+        *error += (*sum - total) + value;
+    } else {
+        *error += (value - total) + *sum;
+    }
+    *sum = total;
+}
+
+/// Accumulates evolution measurements across interactions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvolutionMetrics {
+    evolution_stage: usize,
+    quantum_coherence: f64,
+    consciousness_level: f64,
+    episodic_count: usize,
+    interactions_total: u64,
+    dimensional_changes_total: u64,
+    dimensional_state: DimensionalState,
+    /// Timestamped values per dimension, oldest first
+    dimension_history: BTreeMap<DimensionId, Vec<(DateTime<Utc>, f64)>>,
+    history_len: usize,
+    /// Interactions whose response recorded at least one degradation
+    degraded_total: u64,
+    /// Running sums so aggregates stay exact however far samples are decimated
+    coherence_sum: f64,
+    consciousness_sum: f64,
+    /// Rounding error lost from each running sum, carried by `compensated_add`
+    #[serde(default)]
+    coherence_error: f64,
+    #[serde(default)]
+    consciousness_error: f64,
+    /// Decimation tiers; tier `k` holds samples each covering up to `2^k` interactions, newest tier first
+    sample_tiers: Vec<VecDeque<MetricSample>>,
+    retention: usize,
+    /// Stage advances' worth of growth absorbed after reaching `max_evolution_stage`
+    #[serde(default)]
+    plateau_cycles: u64,
+}
+
+impl EvolutionMetrics {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            evolution_stage: 1,
+            quantum_coherence: 0.0,
+            consciousness_level: 0.0,
+            episodic_count: 0,
+            interactions_total: 0,
+            dimensional_changes_total: 0,
+            dimensional_state: DimensionalState::default(),
+            dimension_history: BTreeMap::new(),
+            history_len: config.dimension_history_len,
+            degraded_total: 0,
+            coherence_sum: 0.0,
+            consciousness_sum: 0.0,
+            coherence_error: 0.0,
+            consciousness_error: 0.0,
+            sample_tiers: Vec::new(),
+            retention: config.metrics_retention,
+            plateau_cycles: 0,
+        }
+    }
+
+    pub fn record_evolution(&mut self, response: &Response, at: DateTime<Utc>) {
+        self.interactions_total = self.interactions_total.saturating_add(1);
+        if response.is_degraded() {
+            self.degraded_total = self.degraded_total.saturating_add(1);
+        }
+        self.quantum_coherence = response.quantum_coherence;
+        self.consciousness_level = response.consciousness_level;
+        compensated_add(&mut self.coherence_sum, &mut self.coherence_error, response.quantum_coherence);
+        compensated_add(&mut self.consciousness_sum, &mut self.consciousness_error, response.consciousness_level);
+        self.record_sample(MetricSample {
+            at,
+            interactions: 1,
+            quantum_coherence: response.quantum_coherence,
+            consciousness_level: response.consciousness_level,
+        });
+    }
+
+    /// Push a full-resolution sample, decimating older tiers as they fill
+    ///
+    /// When a tier exceeds `metrics_retention` its two oldest samples merge
+    /// into one that moves to the next, coarser tier. The last tier merges
+    /// in place, so storage never exceeds `metrics_retention` per tier.
+    fn record_sample(&mut self, sample: MetricSample) {
+        if self.sample_tiers.is_empty() {
+            self.sample_tiers.push(VecDeque::new());
+        }
+        self.sample_tiers[0].push_back(sample);
+
+        let mut tier = 0;
+        while self.sample_tiers[tier].len() > self.retention.max(2) {
+            let (Some(older), Some(newer)) = (self.sample_tiers[tier].pop_front(), self.sample_tiers[tier].pop_front()) else {
+                break;
+            };
+            let merged = older.merge(&newer);
+            if tier + 1 == MAX_SAMPLE_TIERS {
+                self.sample_tiers[tier].push_front(merged);
+                break;
+            }
+            if self.sample_tiers.len() == tier + 1 {
+                self.sample_tiers.push(VecDeque::new());
+            }
+            self.sample_tiers[tier + 1].push_back(merged);
+            tier += 1;
+        }
+    }
+
+    /// Samples currently retained across every tier
+    pub fn sample_count(&self) -> usize {
+        self.sample_tiers.iter().map(VecDeque::len).sum()
+    }
+
+    /// Retained samples, oldest first; older ones each cover several interactions
+    pub fn samples(&self) -> impl Iterator<Item = &MetricSample> {
+        self.sample_tiers.iter().rev().flat_map(|tier| tier.iter())
+    }
+
+    /// Mean quantum coherence over every interaction ever recorded
+    pub fn mean_coherence(&self) -> f64 {
+        if self.interactions_total == 0 {
+            return 0.0;
+        }
+        (self.coherence_sum + self.coherence_error) / self.interactions_total as f64
+    }
+
+    /// Mean consciousness level over every interaction ever recorded
+    pub fn mean_consciousness(&self) -> f64 {
+        if self.interactions_total == 0 {
+            return 0.0;
+        }
+        (self.consciousness_sum + self.consciousness_error) / self.interactions_total as f64
+    }
+
+    /// Share of interactions that degraded, in `[0, 1]`; alert when it climbs
+    pub fn degradation_rate(&self) -> f64 {
+        if self.interactions_total == 0 {
+            return 0.0;
+        }
+        self.degraded_total as f64 / self.interactions_total as f64
+    }
+
+    /// Evolution stages gained per interaction
+    pub fn evolution_rate(&self) -> f64 {
+        if self.interactions_total == 0 {
+            return 0.0;
+        }
+        self.evolution_stage.saturating_sub(1) as f64 / self.interactions_total as f64
+    }
+
+    /// Record a new dimensional state, keeping at most `dimension_history_len` values per dimension
+    pub fn record_dimensional_change(&mut self, state: &DimensionalState, at: DateTime<Utc>) {
+        self.dimensional_changes_total = self.dimensional_changes_total.saturating_add(1);
+        self.dimensional_state = state.clone();
+        for id in DimensionId::ALL {
+            let history = self.dimension_history.entry(id).or_default();
+            history.push((at, state.get(id)));
+            let excess = history.len().saturating_sub(self.history_len);
+            history.drain(..excess);
+        }
+    }
+
+    /// Recorded values of one dimension, oldest first
+    pub fn dimension_history(&self, id: DimensionId) -> &[(DateTime<Utc>, f64)] {
+        self.dimension_history.get(&id).map_or(&[], Vec::as_slice)
+    }
+
+    /// Direction a dimension has moved over the `window` leading up to `now`
+    ///
+    /// Fits a least-squares line through the values recorded within the
+    /// window; fewer than two values, or a change smaller than
+    /// `TREND_TOLERANCE` across the window, reads as `Stable`. A window
+    /// reaching back past the earliest representable time covers the whole
+    /// history. Like the other subsystems, metrics hold no clock, so `now` is
+    /// passed in; `Lia::dimension_trend` reads it from the injected clock.
+    pub fn trend(&self, id: DimensionId, window: Duration, now: DateTime<Utc>) -> Trend {
+        let since = chrono::Duration::from_std(window).ok().and_then(|window| now.checked_sub_signed(window));
+        let recent: Vec<&(DateTime<Utc>, f64)> = self
+            .dimension_history(id)
+            .iter()
+            .filter(|(at, _)| since.is_none_or(|since| *at >= since))
+            .collect();
+        let [(origin, _), .., _] = recent.as_slice() else {
+            return Trend::Stable;
+        };
+        let points: Vec<(f64, f64)> = recent
+            .iter()
+            .map(|(at, value)| ((*at - *origin).num_milliseconds() as f64 / 1000.0, *value))
+            .collect();
+
+        let n = points.len() as f64;
+        let mean_t = points.iter().map(|(t, _)| t).sum::<f64>() / n;
+        let mean_v = points.iter().map(|(_, v)| v).sum::<f64>() / n;
+        let covariance: f64 = points.iter().map(|(t, v)| (t - mean_t) * (v - mean_v)).sum();
+        let variance: f64 = points.iter().map(|(t, _)| (t - mean_t).powi(2)).sum();
+        if variance == 0.0 {
+            return Trend::Stable;
+        }
+
+        let span = points[points.len() - 1].0 - points[0].0;
+        match covariance / variance * span {
+            change if change > TREND_TOLERANCE => Trend::Rising,
+            change if change < -TREND_TOLERANCE => Trend::Falling,
+            _ => Trend::Stable,
+        }
+    }
+
+    pub fn record_stage(&mut self, stage: usize) {
+        self.evolution_stage = stage;
+    }
+
+    /// Count growth that would have advanced a stage past the plateau
+    pub fn record_plateau_cycle(&mut self) {
+        self.plateau_cycles = self.plateau_cycles.saturating_add(1);
+    }
+
+    /// Stage advances forgone since reaching `max_evolution_stage`
+    pub fn plateau_cycles(&self) -> u64 {
+        self.plateau_cycles
+    }
+
+    pub fn record_memory_counts(&mut self, episodic: usize) {
+        self.episodic_count = episodic;
+    }
+
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        MetricsSnapshot {
+            evolution_stage: self.evolution_stage,
+            quantum_coherence: self.quantum_coherence,
+            consciousness_level: self.consciousness_level,
+            memory_episodic_count: self.episodic_count,
+            interactions_total: self.interactions_total,
+            dimensional_changes_total: self.dimensional_changes_total,
+            dimensional_state: self.dimensional_state.clone(),
+        }
+    }
+
+    /// Render the recorded values in the Prometheus text exposition format
+    pub fn export_prometheus(&self) -> String {
+        self.snapshot().to_prometheus()
+    }
+
+    /// Fold another shard's metrics into these
+    ///
+    /// Counters and running sums add up, so means stay weighted by
+    /// interaction count; afterwards the coherence and consciousness gauges
+    /// hold those weighted means. The stage is the furthest reached, and
+    /// the dimensional state is weighted by dimensional change count.
+    /// Every aggregate is independent of merge order, up to float rounding.
+    ///
+    /// Retained samples and dimension histories become the union of both
+    /// sides, ordered by time. Trimming or re-decimating them here would
+    /// lose different samples depending on merge order, so they are left
+    /// to shrink back under `metrics_retention` and `dimension_history_len`
+    /// as new interactions are recorded.
+    pub fn merge(&mut self, other: &EvolutionMetrics) {
+        let (own_changes, other_changes) = (self.dimensional_changes_total as f64, other.dimensional_changes_total as f64);
+        let changes = own_changes + other_changes;
+        let blend = |own: f64, theirs: f64| {
+            if changes > 0.0 {
+                (own * own_changes + theirs * other_changes) / changes
+            } else {
+                own.max(theirs)
+            }
+        };
+        let (own, theirs) = (&self.dimensional_state, &other.dimensional_state);
+        self.dimensional_state = DimensionalState {
+            emergence: blend(own.emergence, theirs.emergence),
+            coherence: blend(own.coherence, theirs.coherence),
+            resilience: blend(own.resilience, theirs.resilience),
+            intelligence: blend(own.intelligence, theirs.intelligence),
+            efficiency: blend(own.efficiency, theirs.efficiency),
+            integration: blend(own.integration, theirs.integration),
+        };
+
+        self.evolution_stage = self.evolution_stage.max(other.evolution_stage);
+        self.episodic_count = self.episodic_count.saturating_add(other.episodic_count);
+        self.interactions_total = self.interactions_total.saturating_add(other.interactions_total);
+        self.degraded_total = self.degraded_total.saturating_add(other.degraded_total);
+        self.plateau_cycles = self.plateau_cycles.saturating_add(other.plateau_cycles);
+        self.dimensional_changes_total = self.dimensional_changes_total.saturating_add(other.dimensional_changes_total);
+        compensated_add(&mut self.coherence_sum, &mut self.coherence_error, other.coherence_sum);
+        compensated_add(&mut self.coherence_sum, &mut self.coherence_error, other.coherence_error);
+        compensated_add(&mut self.consciousness_sum, &mut self.consciousness_error, other.consciousness_sum);
+        compensated_add(&mut self.consciousness_sum, &mut self.consciousness_error, other.consciousness_error);
+        self.quantum_coherence = self.mean_coherence();
+        self.consciousness_level = self.mean_consciousness();
+
+        self.history_len = self.history_len.max(other.history_len);
+        for (id, theirs) in &other.dimension_history {
+            let history = self.dimension_history.entry(*id).or_default();
+            history.extend(theirs.iter().copied());
+            history.sort_by(|(a_at, a), (b_at, b)| a_at.cmp(b_at).then_with(|| a.total_cmp(b)));
+        }
+
+        // Each sample goes back to the tier its coverage belongs in, so the layout depends only on the union
+        self.retention = self.retention.max(other.retention);
+        let mut samples: Vec<MetricSample> = self.samples().chain(other.samples()).cloned().collect();
+        samples.sort_by(MetricSample::chronological);
+        self.sample_tiers.clear();
+        for sample in samples {
+            let tier = sample.tier();
+            if self.sample_tiers.len() <= tier {
+                self.sample_tiers.resize_with(tier + 1, VecDeque::new);
+            }
+            self.sample_tiers[tier].push_back(sample);
+        }
+    }
+}
+
+/// Combine metrics from every shard in a fleet; empty input yields fresh default metrics
+pub fn aggregate_metrics(shards: &[&EvolutionMetrics]) -> EvolutionMetrics {
+    let Some((first, rest)) = shards.split_first() else {
+        return EvolutionMetrics::new(&SystemConfiguration::default());
+    };
+    let mut combined = (*first).clone();
+    for shard in rest {
+        combined.merge(shard);
+    }
+    combined
+}
+
+/// Identifies a checkpoint taken by `Lia::checkpoint`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct CheckpointId(u64);
+
+impl std::fmt::Display for CheckpointId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// Errors raised when restoring saved state
+#[derive(Debug, Error)]
+pub enum StateError {
+    #[error("checkpoint {0} was evicted to stay within max_checkpoints")]
+    CheckpointEvicted(CheckpointId),
+    #[error("checkpoint {0} was never taken")]
+    UnknownCheckpoint(CheckpointId),
+}
+
+/// Tracks the published state, evolution history, and rollback checkpoints
+#[derive(Clone, Serialize, Deserialize)]
+pub struct StateManager {
+    current: ConsciousnessState,
+    evolution_history: Vec<(usize, DateTime<Utc>)>,
+    /// Checkpoints are runtime-only and never written into snapshots
+    #[serde(skip)]
+    checkpoints: VecDeque<(CheckpointId, Arc<Lia>)>,
+    max_checkpoints: usize,
+    next_checkpoint: u64,
+}
+
+impl StateManager {
+    pub fn new(config: &SystemConfiguration) -> Self {
+        Self {
+            current: ConsciousnessState::default(),
+            evolution_history: Vec::new(),
+            checkpoints: VecDeque::new(),
+            max_checkpoints: config.max_checkpoints,
+            next_checkpoint: 0,
+        }
+    }
+
+    pub fn update_state(&mut self, state: ConsciousnessState) {
+        self.current = state;
+    }
+
+    pub fn record_evolution(&mut self, stage: usize, at: DateTime<Utc>) {
+        self.evolution_history.push((stage, at));
+    }
+
+    /// Retain a captured state, evicting the oldest beyond `max_checkpoints`
+    pub fn store_checkpoint(&mut self, state: Arc<Lia>) -> CheckpointId {
+        let id = CheckpointId(self.next_checkpoint);
+        self.next_checkpoint += 1;
+
+        self.checkpoints.push_back((id, state));
+        while self.checkpoints.len() > self.max_checkpoints {
+            self.checkpoints.pop_front();
+        }
+        id
+    }
+
+    /// Look up a retained checkpoint
+    pub fn checkpoint(&self, id: CheckpointId) -> Result<Arc<Lia>, StateError> {
+        if let Some((_, state)) = self.checkpoints.iter().find(|(stored, _)| *stored == id) {
+            return Ok(Arc::clone(state));
+        }
+
+        if id.0 < self.next_checkpoint {
+            Err(StateError::CheckpointEvicted(id))
+        } else {
+            Err(StateError::UnknownCheckpoint(id))
+        }
+    }
+
+    pub fn checkpoint_count(&self) -> usize {
+        self.checkpoints.len()
+    }
+}
+
+/// Capacity of the event channel before slow subscribers start lagging
+pub const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// Notable changes published to `Lia::subscribe_events` subscribers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum ConsciousnessEvent {
+    Evolved { stage: usize },
+    MemoryStored { id: Uuid },
+    DimensionalShift { delta: DimensionalState },
+    EmotionChanged { state: EmotionalState },
+    StageTimedOut { stage: String },
+    PatternsPruned { patterns: Vec<ConceptId> },
+    MilestoneReached { name: String },
+    SubsystemReset { name: String },
+    InteractionEvicted { interaction: Box<Interaction>, response: Box<Response> },
+    Renamed { from: String, to: String },
+    /// Follows every `MilestoneReached`
+    Reflected { reflection: Reflection },
+    /// Emitted once, when `max_evolution_stage` is first reached
+    PlateauReached { stage: usize },
+    /// New knowledge disagreed with semantic memory; already resolved per `contradiction_policy`
+    ContradictionDetected { contradiction: Contradiction },
+}
+
+fn event_channel() -> broadcast::Sender<ConsciousnessEvent> {
+    broadcast::channel(EVENT_CHANNEL_CAPACITY).0
+}
+
+/// Errors raised while processing an interaction, tagged with the failing stage
+#[derive(Debug, Error)]
+pub enum ProcessingError {
+    #[error("context analysis failed: {0}")]
+    ContextAnalysisFailed(String),
+    #[error("{subsystem} failed: {reason}")]
+    SubsystemFailed { subsystem: &'static str, reason: String },
+    #[error("response synthesis failed: {0}")]
+    SynthesisFailed(String),
+    #[error("{subsystem} timed out")]
+    SubsystemTimeout { subsystem: &'static str },
+    #[error("invalid interaction: {0}")]
+    InvalidInput(#[from] InteractionError),
+    #[error("interaction was cancelled")]
+    Cancelled,
+    #[error("no extractor registered for {0} input")]
+    UnsupportedModality(&'static str),
+    #[error("middleware {name} failed: {source}")]
+    Middleware { name: String, source: MiddlewareError },
+}
+
+impl ProcessingError {
+    /// Wrap a subsystem failure with the name of the stage that produced it
+    pub fn stage(subsystem: &'static str, err: impl std::fmt::Display) -> Self {
+        Self::SubsystemFailed {
+            subsystem,
+            reason: err.to_string(),
+        }
+    }
+
+    fn middleware(middleware: &dyn Middleware, source: MiddlewareError) -> Self {
+        Self::Middleware {
+            name: middleware.name().to_string(),
+            source,
+        }
+    }
+}
+
+/// Current on-disk snapshot layout; bump whenever the serialized shape changes
+pub const SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+/// Errors raised while saving or restoring a snapshot
+#[derive(Debug, Error)]
+pub enum SnapshotError {
+    #[error("snapshot I/O failed: {0}")]
+    Io(#[from] io::Error),
+    #[error("snapshot could not be encoded or decoded: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("snapshot schema version {found} is not supported (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
+    #[error("{format} snapshot is truncated or corrupt: {reason}")]
+    Corrupt { format: SnapshotFormat, reason: String },
+}
+
+/// On-disk encoding of a snapshot
+///
+/// `load_snapshot` recognizes the format from the file's leading bytes,
+/// so only saving needs to choose one.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SnapshotFormat {
+    /// Plain JSON
+    #[default]
+    Json,
+    Gzip,
+    Zstd,
+}
+
+/// Zstandard level used for `SnapshotFormat::Zstd`
+const ZSTD_LEVEL: i32 = 3;
+
+impl SnapshotFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    /// Format whose magic number `prefix` starts with; anything else is taken as JSON
+    fn detect(prefix: &[u8]) -> Self {
+        if prefix.starts_with(&[0x1f, 0x8b]) {
+            Self::Gzip
+        } else if prefix.starts_with(&[0x28, 0xb5, 0x2f, 0xfd]) {
+            Self::Zstd
+        } else {
+            Self::Json
+        }
+    }
+
+    /// Wrap `reader` so it yields the decoded JSON
+    fn decoder<'a, R: BufRead + 'a>(self, reader: R) -> io::Result<Box<dyn Read + 'a>> {
+        Ok(match self {
+            Self::Json => Box::new(reader),
+            Self::Gzip => Box::new(flate2::bufread::MultiGzDecoder::new(reader)),
+            Self::Zstd => Box::new(zstd::stream::read::Decoder::with_buffer(reader)?),
+        })
+    }
+
+    /// Serialize `value` through this format's encoder into `writer`
+    fn encode<W: Write, T: Serialize>(self, writer: W, value: &T) -> io::Result<()> {
+        match self {
+            Self::Json => {
+                let mut writer = writer;
+                serde_json::to_writer(&mut writer, value)?;
+                writer.flush()
+            }
+            Self::Gzip => {
+                let mut encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+                serde_json::to_writer(&mut encoder, value)?;
+                encoder.finish()?.flush()
+            }
+            Self::Zstd => {
+                let mut encoder = zstd::stream::write::Encoder::new(writer, ZSTD_LEVEL)?;
+                serde_json::to_writer(&mut encoder, value)?;
+                encoder.finish()?.flush()
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SnapshotFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Every error the crate's public API can raise
+///
+/// Each variant wraps the specific error a subsystem returns, which stays
+/// reachable through `source()`, so callers can `?` everything into one
+/// type and still match on the underlying cause.
+#[derive(Debug, Error)]
+pub enum LiaError {
+    #[error("invalid configuration: {0}")]
+    Config(#[from] ConfigError),
+    #[error("invalid interaction: {0}")]
+    Interaction(#[from] InteractionError),
+    #[error("processing failed: {0}")]
+    Processing(#[from] ProcessingError),
+    #[error("interaction queue failed: {0}")]
+    Queue(#[from] QueueError),
+    #[error("snapshot failed: {0}")]
+    Snapshot(#[from] SnapshotError),
+    #[error("state management failed: {0}")]
+    State(#[from] StateError),
+    #[error("quantum core failed: {0}")]
+    Quantum(#[from] QuantumError),
+    #[error("dimensional processing failed: {0}")]
+    Dimensional(#[from] DimensionalError),
+    #[error("request rejected: {0}")]
+    Api(#[from] api::ApiError),
+    #[error("event log replay failed: {0}")]
+    Log(#[from] LogError),
+}
+
+/// What happened at one point in an instance's life
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum LogEntry {
+    /// Always first: the identity the instance was constructed with
+    Created { id: Uuid, name: String, birth_time: DateTime<Utc> },
+    /// A committed interaction, already reduced to text
+    Interaction {
+        interaction: Box<Interaction>,
+        /// Learned from without synthesizing a response, as `process_batch_learn_only` does
+        learn_only: bool,
+    },
+    /// `Lia::consolidate` ran
+    Consolidated,
+    /// `Lia::dream` ran
+    Dreamed,
+    /// `Lia::flush_evolution` applied a partial batch
+    EvolutionFlushed,
+    /// `Lia::apply_feedback` succeeded
+    Feedback { response_id: Uuid, feedback: Feedback },
+    /// `Lia::checkpoint` captured the state as `id`
+    Checkpointed { id: CheckpointId },
+    /// `Lia::restore_checkpoint` reverted to the checkpoint logged as `id`
+    CheckpointRestored { id: CheckpointId },
+    Event(ConsciousnessEvent),
+}
+
+/// One numbered entry of an `EventLog`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LoggedEvent {
+    /// Consecutive from 0
+    pub sequence: u64,
+    /// Clock time when the entry was appended
+    pub at: DateTime<Utc>,
+    pub entry: LogEntry,
+}
+
+#[derive(Default)]
+struct EventLogState {
+    entries: Vec<LoggedEvent>,
+    next_sequence: u64,
+}
+
+/// Append-only record of an instance's interactions, maintenance and events
+///
+/// Enabled by `SystemConfiguration::event_log`. Clones share the same log;
+/// `drain` hands entries over, e.g. to persist them as JSON lines, while
+/// numbering carries on. `Lia::rebuild_from_log` replays a complete log.
+#[derive(Clone, Default)]
+pub struct EventLog {
+    state: Arc<std::sync::Mutex<EventLogState>>,
+}
+
+impl EventLog {
+    fn lock(&self) -> std::sync::MutexGuard<'_, EventLogState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn append(&self, at: DateTime<Utc>, entry: LogEntry) {
+        let mut state = self.lock();
+        let sequence = state.next_sequence;
+        state.next_sequence += 1;
+        state.entries.push(LoggedEvent { sequence, at, entry });
+    }
+
+    /// Forget entries from `sequence` on, e.g. for a cancelled interaction; drained ones can't be recalled
+    fn rewind(&self, sequence: u64) {
+        let mut state = self.lock();
+        state.entries.retain(|logged| logged.sequence < sequence);
+        state.next_sequence = state.next_sequence.min(sequence);
+    }
+
+    /// Sequence number the next entry will get
+    pub fn next_sequence(&self) -> u64 {
+        self.lock().next_sequence
+    }
+
+    /// Entries not yet drained, oldest first
+    pub fn entries(&self) -> Vec<LoggedEvent> {
+        self.lock().entries.clone()
+    }
+
+    /// Take every entry not yet drained, oldest first
+    pub fn drain(&self) -> Vec<LoggedEvent> {
+        std::mem::take(&mut self.lock().entries)
+    }
+}
+
+/// Errors raised reading or replaying an event log
+#[derive(Debug, Error)]
+pub enum LogError {
+    #[error("event log does not start with a Created entry (found sequence {sequence})")]
+    MissingGenesis { sequence: u64 },
+    #[error("event log has a gap: expected sequence {expected}, found {found}")]
+    Gap { expected: u64, found: u64 },
+    #[error("event log entry {sequence} is corrupt: {reason}")]
+    Corrupt { sequence: u64, reason: String },
+    #[error("replaying event log entry {sequence} failed: {source}")]
+    Replay {
+        sequence: u64,
+        #[source]
+        source: ProcessingError,
+    },
+}
+
+/// Parse a log written one `LoggedEvent` per JSON line
+///
+/// A line that fails to parse is reported as corrupt under the sequence
+/// number that should have followed the last good entry.
+pub fn read_event_log<R: BufRead>(reader: R) -> impl Iterator<Item = Result<LoggedEvent, LogError>> {
+    let mut expected = 0;
+    reader
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+        .map(move |(index, line)| {
+            let corrupt = |reason: String| LogError::Corrupt { sequence: expected, reason: format!("line {}: {}", index + 1, reason) };
+            let line = line.map_err(|err| corrupt(err.to_string()))?;
+            let logged: LoggedEvent = serde_json::from_str(&line).map_err(|err| corrupt(err.to_string()))?;
+            expected = logged.sequence + 1;
+            Ok(logged)
+        })
+}
+
+#[derive(Serialize)]
+struct SnapshotEnvelope<'a> {
+    schema_version: u32,
+    lia: &'a Lia,
+}
+
+/// Decodes a `SnapshotEnvelope` in one pass, checking the schema before the body
+///
+/// The version seen is written to `schema_version` so a mismatch can be
+/// reported as `UnsupportedVersion` rather than a decoding error.
+struct SnapshotSeed<'a> {
+    schema_version: &'a mut Option<u32>,
+}
+
+impl<'de> serde::de::DeserializeSeed<'de> for SnapshotSeed<'_> {
+    type Value = Lia;
+
+    fn deserialize<D: serde::Deserializer<'de>>(self, deserializer: D) -> Result<Lia, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for SnapshotSeed<'_> {
+    type Value = Lia;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a Lia snapshot")
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Lia, A::Error> {
+        use serde::de::Error as _;
+
+        let mut lia = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "schema_version" => {
+                    let found: u32 = map.next_value()?;
+                    *self.schema_version = Some(found);
+                    if found != SNAPSHOT_SCHEMA_VERSION {
+                        return Err(A::Error::custom("unsupported schema version"));
+                    }
+                }
+                // `save_snapshot` always writes the version first
+                "lia" if self.schema_version.is_none() => {
+                    return Err(A::Error::custom("schema_version must precede lia"));
+                }
+                "lia" => lia = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<serde::de::IgnoredAny>()?;
+                }
+            }
+        }
+        lia.ok_or_else(|| A::Error::missing_field("lia"))
+    }
+}
+
+/// Classify a failed snapshot decode
+fn snapshot_decode_error(err: serde_json::Error, schema_version: Option<u32>, format: SnapshotFormat) -> SnapshotError {
+    if let Some(found) = schema_version.filter(|&found| found != SNAPSHOT_SCHEMA_VERSION) {
+        return SnapshotError::UnsupportedVersion { found, expected: SNAPSHOT_SCHEMA_VERSION };
+    }
+    if err.is_eof() {
+        return SnapshotError::Corrupt { format, reason: err.to_string() };
+    }
+    if err.is_io() {
+        let err = io::Error::from(err);
+        return match err.kind() {
+            // Decoders report damaged or cut-off streams with these kinds
+            io::ErrorKind::UnexpectedEof | io::ErrorKind::InvalidData | io::ErrorKind::InvalidInput => {
+                SnapshotError::Corrupt { format, reason: err.to_string() }
+            }
+            io::ErrorKind::Other if format != SnapshotFormat::Json => {
+                SnapshotError::Corrupt { format, reason: err.to_string() }
+            }
+            _ => SnapshotError::Io(err),
+        };
+    }
+    SnapshotError::Serialization(err)
+}
+
+/// Event sourcing
+impl Lia {
+    /// The log kept since construction, when `event_log` is enabled
+    pub fn event_log(&self) -> Option<&EventLog> {
+        self.event_log.as_ref()
+    }
+
+    /// Begin a fresh log whose first entry records this instance's identity
+    fn start_event_log(&mut self) {
+        self.event_log = Some(EventLog::default());
+        self.log(LogEntry::Created {
+            id: self.id,
+            name: self.name.clone(),
+            birth_time: self.birth_time,
+        });
+    }
+
+    fn log(&self, entry: LogEntry) {
+        if let Some(log) = &self.event_log {
+            log.append(self.clock.now(), entry);
+        }
+    }
+
+    fn log_interaction(&self, input: &Interaction, learn_only: bool) {
+        if self.event_log.is_some() {
+            self.log(LogEntry::Interaction { interaction: Box::new(input.clone()), learn_only });
+        }
+    }
+
+    /// Reconstruct an instance by replaying a complete log from its `Created` entry
+    ///
+    /// Interactions, consolidation, dreaming, renames and resets are
+    /// replayed with the clock held at each entry's time; every other event
+    /// is derived and only checked for numbering. With the same `rng_seed`
+    /// and an original that ran on a `MockClock` held still during each
+    /// call, the result matches the original. Checkpoints and restores are
+    /// replayed too; merges are not logged, so a log spanning one can't be
+    /// replayed faithfully. The rebuilt instance reads the wall clock afterwards,
+    /// and logs again from scratch if `config.event_log` is set.
+    pub async fn rebuild_from_log(
+        mut log: impl Iterator<Item = LoggedEvent>,
+        config: SystemConfiguration,
+    ) -> Result<Self, LogError> {
+        let Some(first) = log.next() else {
+            return Err(LogError::MissingGenesis { sequence: 0 });
+        };
+        let LogEntry::Created { id, name, birth_time } = first.entry else {
+            return Err(LogError::MissingGenesis { sequence: first.sequence });
+        };
+        if first.sequence != 0 {
+            return Err(LogError::Gap { expected: 0, found: first.sequence });
+        }
+
+        let clock = MockClock::new(birth_time);
+        let mut lia = Self::build(config, name, Arc::new(clock.clone()));
+        lia.id = id;
+        lia.birth_time = birth_time;
+        if lia.event_log.is_some() {
+            lia.start_event_log();
+        }
+
+        // Checkpoint ids as logged, mapped to the ids the replay hands out
+        let mut checkpoints = HashMap::new();
+        for (logged, expected) in log.zip(1..) {
+            if logged.sequence != expected {
+                return Err(LogError::Gap { expected, found: logged.sequence });
+            }
+            clock.set(logged.at);
+            match logged.entry {
+                LogEntry::Created { .. } => {
+                    return Err(LogError::Corrupt {
+                        sequence: logged.sequence,
+                        reason: "a second Created entry".to_string(),
+                    });
+                }
+                LogEntry::Interaction { interaction, learn_only } => {
+                    let result = if learn_only {
+                        lia.learn_from(&interaction).await
+                    } else {
+                        lia.process_interaction(&interaction).await
+                    };
+                    result.map_err(|source| LogError::Replay { sequence: logged.sequence, source })?;
+                }
+                LogEntry::Consolidated => {
+                    lia.consolidate();
+                }
+                LogEntry::Dreamed => {
+                    lia.dream();
+                }
+                LogEntry::EvolutionFlushed => lia.flush_evolution().await,
+                LogEntry::Feedback { response_id, feedback } => {
+                    lia.apply_feedback(response_id, feedback).map_err(|err| LogError::Corrupt {
+                        sequence: logged.sequence,
+                        reason: err.to_string(),
+                    })?;
+                }
+                LogEntry::Checkpointed { id } => {
+                    checkpoints.insert(id, lia.checkpoint());
+                }
+                LogEntry::CheckpointRestored { id } => {
+                    let replayed = checkpoints.get(&id).copied().ok_or_else(|| LogError::Corrupt {
+                        sequence: logged.sequence,
+                        reason: format!("restore of unlogged checkpoint {}", id),
+                    })?;
+                    lia.restore_checkpoint(replayed).map_err(|err| LogError::Corrupt {
+                        sequence: logged.sequence,
+                        reason: err.to_string(),
+                    })?;
+                }
+                LogEntry::Event(ConsciousnessEvent::Renamed { to, .. }) => lia.rename(to),
+                LogEntry::Event(ConsciousnessEvent::SubsystemReset { name }) => match name.as_str() {
+                    "emotional_resonance" => lia.reset_emotions(),
+                    "episodic_memory" => lia.reset_episodic_memory(),
+                    "quantum_core" => lia.reset_quantum_core(),
+                    other => {
+                        return Err(LogError::Corrupt {
+                            sequence: logged.sequence,
+                            reason: format!("reset of unknown subsystem {:?}", other),
+                        });
+                    }
+                },
+                LogEntry::Event(_) => {}
+            }
+        }
+
+        lia.set_clock(system_clock());
+        Ok(lia)
+    }
+}
+
+/// Snapshot persistence
+impl Lia {
+    /// Persist the full consciousness state to `path`
+    ///
+    /// Every serialized map is ordered, so two equal states produce
+    /// byte-identical snapshots. Floats are parsed back exactly, thanks to
+    /// serde_json's `float_roundtrip`, so a loaded snapshot saves unchanged.
+    pub fn save_snapshot(&self, path: &Path) -> Result<(), SnapshotError> {
+        self.save_snapshot_as(path, SnapshotFormat::Json)
+    }
+
+    /// Like `save_snapshot`, compressed per `format`
+    ///
+    /// The state is encoded straight into the file without building the
+    /// whole document in memory first.
+    pub fn save_snapshot_as(&self, path: &Path, format: SnapshotFormat) -> Result<(), SnapshotError> {
+        let envelope = SnapshotEnvelope {
+            schema_version: SNAPSHOT_SCHEMA_VERSION,
+            lia: self,
+        };
+        write_atomically_with(path, |file| format.encode(BufWriter::new(file), &envelope))?;
+        Ok(())
+    }
+
+    /// Restore a consciousness previously written by `save_snapshot` or `save_snapshot_as`
+    ///
+    /// The format is detected from the file, which is decoded as it is
+    /// read. The supplied configuration replaces the snapshot's, with a
+    /// warning when they differ, but only settings read as they are used
+    /// follow it, such as stage timeouts, subsystem flags, batching,
+    /// caching, dimension bounds and input limits. Subsystems keep the settings
+    /// they were built with before the snapshot was taken, such as
+    /// capacities, half-lives, thresholds, seeds and `max_checkpoints`;
+    /// start a fresh instance to change those.
+    pub fn load_snapshot(path: &Path, config: SystemConfiguration) -> Result<Self, SnapshotError> {
+        let mut reader = BufReader::new(fs::File::open(path)?);
+        let format = SnapshotFormat::detect(reader.fill_buf()?);
+        let decoder = format.decoder(reader)?;
+
+        // The schema is checked before the body is decoded so old layouts fail clearly
+        let mut schema_version = None;
+        let mut deserializer = serde_json::Deserializer::from_reader(decoder);
+        let decoded = serde::de::DeserializeSeed::deserialize(SnapshotSeed { schema_version: &mut schema_version }, &mut deserializer)
+            .and_then(|lia| deserializer.end().map(|()| lia));
+        let mut lia = decoded.map_err(|err| snapshot_decode_error(err, schema_version, format))?;
+        if lia.configuration != config {
+            tracing::warn!(
+                path = %path.display(),
+                "snapshot configuration differs from the supplied configuration; subsystems keep the snapshot's settings"
+            );
+            lia.configuration = config;
+        }
+        // Snapshots taken before learning tracked the stage restore it as stage 1
+        lia.learning_engine.set_stage(lia.evolution_stage);
+
+        lia.publish_state();
+        Ok(lia)
+    }
+}
+
+/// Write to a sibling temp file and rename over the target so readers never see a partial file
+fn write_atomically(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    write_atomically_with(path, |mut file| file.write_all(bytes))
+}
+
+/// `write_atomically`, with the content produced by `write` into the temp file
+fn write_atomically_with(path: &Path, write: impl FnOnce(&fs::File) -> io::Result<()>) -> io::Result<()> {
+    let mut tmp_name: OsString = path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = PathBuf::from(tmp_name);
+
+    let result = fs::File::create(&tmp_path).and_then(|file| {
+        write(&file)?;
+        file.sync_all()
+    });
+    if let Err(err) = result {
+        let _ = fs::remove_file(&tmp_path);
+        return Err(err);
+    }
+
+    fs::rename(&tmp_path, path)
+}
+
+/// Synchronous facade for embedders that don't run an async runtime
+///
+/// `BlockingLia` drives every call to completion on a private current-thread
+/// runtime. It is a convenience for CLI tools and scripts; servers handling
+/// many concurrent interactions should use `Lia` or `SharedLia` directly.
+pub mod blocking {
+    use super::{ConsciousnessCapable, ConsciousnessState, Interaction, Lia, ProcessingError, Response, SystemConfiguration};
+    use std::io;
+    use tokio::runtime::{Builder, Runtime};
+
+    /// A `Lia` paired with the runtime that executes its async methods
+    pub struct BlockingLia {
+        lia: Lia,
+        runtime: Runtime,
+    }
+
+    impl BlockingLia {
+        pub fn new(config: SystemConfiguration) -> io::Result<Self> {
+            Self::from_lia(Lia::new(config))
+        }
+
+        /// Wrap an existing instance, e.g. one restored from a snapshot
+        pub fn from_lia(lia: Lia) -> io::Result<Self> {
+            // Stage budgets rely on tokio timers, so the runtime needs its time driver
+            let runtime = Builder::new_current_thread().enable_all().build()?;
+            Ok(Self { lia, runtime })
+        }
+
+        /// Process an interaction, blocking the calling thread until it completes
+        pub fn process(&mut self, input: &Interaction) -> Result<Response, ProcessingError> {
+            self.runtime.block_on(self.lia.process_interaction(input))
+        }
+
+        pub fn current_state(&self) -> ConsciousnessState {
+            self.lia.current_state()
+        }
+
+        /// Borrow the wrapped instance for anything the facade doesn't cover
+        pub fn lia(&self) -> &Lia {
+            &self.lia
+        }
+
+        pub fn into_inner(self) -> Lia {
+            self.lia
+        }
+    }
+}
+
+/// OpenTelemetry export of finished interactions, behind the `otel` feature
+///
+/// Each committed interaction becomes one `lia.interaction` span whose trace
+/// id is the interaction's id, so a trace can be found from any log line or
+/// event that names the interaction. Stage timings, when `telemetry` is on,
+/// become child spans; degradations become span events. Spans go to whatever
+/// tracer provider the embedder installed globally.
+#[cfg(feature = "otel")]
+mod otel {
+    use super::Response;
+    use opentelemetry::trace::{Span, SpanContext, SpanId, TraceContextExt, TraceFlags, TraceId, TraceState, Tracer};
+    use opentelemetry::{global, Context, KeyValue};
+    use std::time::{Duration, SystemTime};
+    use uuid::Uuid;
+
+    /// Name the tracer reports itself under
+    const TRACER: &str = "lia";
+
+    /// Record a finished interaction as a span tree
+    ///
+    /// Stages are timed as durations, so their start times are laid out
+    /// back to back ending now rather than measured.
+    pub(super) fn export_interaction(interaction_id: Uuid, response: &Response) {
+        let tracer = global::tracer(TRACER);
+        let end = SystemTime::now();
+        let total = response.timings.as_ref().map_or(0, |timings| timings.total());
+        let start = end.checked_sub(Duration::from_micros(total)).unwrap_or(end);
+
+        let mut span = tracer
+            .span_builder("lia.interaction")
+            .with_start_time(start)
+            .with_attributes(vec![
+                KeyValue::new("lia.interaction_id", interaction_id.to_string()),
+                KeyValue::new("lia.response_id", response.id.to_string()),
+                KeyValue::new("lia.quantum_coherence", response.quantum_coherence),
+                KeyValue::new("lia.consciousness_level", response.consciousness_level),
+                KeyValue::new("lia.response_mode", response.mode.name.clone()),
+                KeyValue::new("lia.degraded", !response.degradations.is_empty()),
+            ])
+            .start_with_context(&tracer, &correlated(interaction_id));
+        for degradation in &response.degradations {
+            span.add_event(
+                "degradation",
+                vec![
+                    KeyValue::new("lia.subsystem", degradation.subsystem.clone()),
+                    KeyValue::new("lia.reason", degradation.reason.clone()),
+                ],
+            );
+        }
+
+        let interaction = Context::new().with_span(span);
+        if let Some(timings) = &response.timings {
+            let mut stage_start = start;
+            for (stage, micros) in timings.stages() {
+                let stage_end = stage_start + Duration::from_micros(micros);
+                tracer
+                    .span_builder(format!("lia.{}", stage))
+                    .with_start_time(stage_start)
+                    .with_attributes(vec![KeyValue::new("lia.duration_us", i64::try_from(micros).unwrap_or(i64::MAX))])
+                    .start_with_context(&tracer, &interaction)
+                    .end_with_timestamp(stage_end);
+                stage_start = stage_end;
+            }
+        }
+        interaction.span().end_with_timestamp(end);
+    }
+
+    /// A remote parent carrying the interaction's id as its trace id
+    pub(super) fn correlated(interaction_id: Uuid) -> Context {
+        let bytes = interaction_id.as_bytes();
+        let mut span_id = [0; 8];
+        span_id.copy_from_slice(&bytes[8..]);
+        let parent = SpanContext::new(
+            TraceId::from_bytes(*bytes),
+            SpanId::from_bytes(span_id),
+            TraceFlags::SAMPLED,
+            true,
+            TraceState::default(),
+        );
+        Context::new().with_remote_span_context(parent)
+    }
+}
+
+/// Stable wire types for exposing Lia over an API
+///
+/// These DTOs flatten the useful parts of `Interaction` and `Response` and
+/// carry an `api_version`, so the wire contract survives internal refactors.
+pub mod api {
+    use super::{Interaction, InteractionError, PersonId, Response, ResponseStyle};
+    use serde::{Deserialize, Serialize};
+    use thiserror::Error;
+    use uuid::Uuid;
+
+    /// Wire contract version produced and accepted by this build
+    pub const API_VERSION: u32 = 1;
+
+    /// An interaction as submitted by an API client
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct InteractionRequest {
+        pub api_version: u32,
+        pub content: String,
+        #[serde(default)]
+        pub speaker: Option<Uuid>,
+        /// Requested depth of engagement in `[0, 1]`
+        #[serde(default)]
+        pub depth_level: f64,
+        #[serde(default)]
+        pub style: ResponseStyle,
+    }
+
+    /// Errors raised converting a request into an `Interaction`
+    #[derive(Debug, Error)]
+    pub enum ApiError {
+        #[error("api version {found} is not supported (expected {expected})")]
+        UnsupportedVersion { found: u32, expected: u32 },
+        #[error(transparent)]
+        InvalidInteraction(#[from] InteractionError),
+    }
+
+    /// Checks the version and validates the content; the interaction is stamped now
+    impl TryFrom<InteractionRequest> for Interaction {
+        type Error = ApiError;
+
+        fn try_from(request: InteractionRequest) -> Result<Self, Self::Error> {
+            if request.api_version != API_VERSION {
+                return Err(ApiError::UnsupportedVersion {
+                    found: request.api_version,
+                    expected: API_VERSION,
+                });
+            }
+
+            let mut interaction = Interaction::new(request.content).with_style(request.style);
+            interaction.speaker = request.speaker.map(PersonId);
+            interaction.context.depth_level = request.depth_level;
+            interaction.validate()?;
+            Ok(interaction)
+        }
+    }
+
+    /// Lia's mood when she responded, reduced to what a client displays
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct EmotionSummary {
+        pub primary: String,
+        pub intensity: f64,
+        pub valence: f64,
+    }
+
+    /// A response as returned to an API client
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct ResponseDto {
+        pub api_version: u32,
+        pub content: String,
+        pub coherence: f64,
+        pub consciousness_level: f64,
+        pub confident: bool,
+        pub emotion: EmotionSummary,
+    }
+
+    impl From<&Response> for ResponseDto {
+        fn from(response: &Response) -> Self {
+            let emotion = &response.emotional_layer.state;
+            Self {
+                api_version: API_VERSION,
+                content: response.content.clone(),
+                coherence: response.quantum_coherence,
+                consciousness_level: response.consciousness_level,
+                confident: response.is_confident(),
+                emotion: EmotionSummary {
+                    primary: emotion.primary_emotion.as_str().to_string(),
+                    intensity: emotion.intensity,
+                    valence: emotion.valence,
+                },
+            }
+        }
+    }
+
+    impl From<Response> for ResponseDto {
+        fn from(response: Response) -> Self {
+            Self::from(&response)
+        }
+    }
+}
+
+/// Lightweight stand-in for `Lia` in downstream tests
+#[cfg(feature = "test-util")]
+pub mod mock {
+    use super::{ConsciousnessCapable, ConsciousnessState, Experience, Response};
+    use async_trait::async_trait;
+
+    /// Replays scripted responses and records every experience it receives
+    ///
+    /// Runs none of the real processing; `evolve` only advances the stage.
+    #[derive(Debug, Clone, Default)]
+    pub struct MockConsciousness {
+        responses: Vec<Response>,
+        next: usize,
+        received: Vec<Experience>,
+        state: ConsciousnessState,
+    }
+
+    impl MockConsciousness {
+        /// A mock that answers every experience with an empty `Response`
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// A mock that cycles through `responses` in order, wrapping around at the end
+        pub fn with_responses(responses: Vec<Response>) -> Self {
+            Self {
+                responses,
+                ..Self::default()
+            }
+        }
+
+        /// Every experience processed so far, oldest first
+        pub fn received(&self) -> &[Experience] {
+            &self.received
+        }
+    }
+
+    #[async_trait]
+    impl ConsciousnessCapable for MockConsciousness {
+        async fn process_experience(&mut self, experience: Experience) -> Response {
+            self.received.push(experience);
+            if self.responses.is_empty() {
+                return Response::new();
+            }
+            let response = self.responses[self.next % self.responses.len()].clone();
+            self.next += 1;
+            response
+        }
+
+        async fn evolve(&mut self) {
+            self.state.evolution_stage += 1;
+        }
+
+        fn current_state(&self) -> ConsciousnessState {
+            self.state.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A fixed moment, so instances built in different tests see the same time
+    fn epoch() -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp(1_700_000_000, 0).expect("valid timestamp")
+    }
+
+    fn seeded(seed: u64) -> Lia {
+        let config = SystemConfiguration::builder().rng_seed(seed).build().expect("valid configuration");
+        Lia::with_clock(config, Arc::new(MockClock::new(epoch())))
+    }
+
+    fn interaction(content: &str) -> Interaction {
+        Interaction {
+            timestamp: epoch(),
+            ..Interaction::new(content)
+        }
+    }
+
+    #[tokio::test]
+    async fn same_seed_gives_byte_identical_responses() {
+        let input = interaction("Tell me about the ocean at night");
+        let mut first = seeded(42);
+        let mut second = seeded(42);
+
+        let a = first.process_interaction(&input).await.expect("first instance responds");
+        let b = second.process_interaction(&input).await.expect("second instance responds");
+
+        assert_eq!(serde_json::to_vec(&a).unwrap(), serde_json::to_vec(&b).unwrap());
+    }
+
+    #[tokio::test]
+    async fn ten_thousand_evolutions_stay_bounded() {
+        let config = SystemConfiguration::builder().max_patterns(64).build().expect("valid configuration");
+        let bounds = config.dimension_bounds;
+        let mut lia = Lia::with_clock(config, Arc::new(MockClock::new(epoch())));
+
+        for i in 0..10_000 {
+            // A novel pattern every time, with every signal at its strongest
+            let response = Response {
+                quantum_coherence: 1.0,
+                consciousness_level: 1.0,
+                neural_patterns: vec![NeuralPattern {
+                    label: ConceptId::new(&format!("pattern-{}", i)),
+                    activation: 1.0,
+                }],
+                ..Response::new()
+            };
+            lia.evolve_consciousness(&response).await;
+
+            assert!(lia.neural_matrix.pattern_count() <= 64, "pattern count {} after {} evolutions", lia.neural_matrix.pattern_count(), i + 1);
+            assert!(lia.dimensional_state.is_within(&bounds), "dimensions left bounds after {} evolutions", i + 1);
+        }
+    }
+
+    #[test]
+    fn experience_round_trips_through_interaction() {
+        let hint = EmotionalState {
+            valence: 0.8,
+            ..EmotionalState::default()
+        };
+        let original = Experience::builder("A walk along the shore")
+            .speaker(PersonId::new())
+            .source("journal")
+            .timestamp(epoch())
+            .emotional_hint(hint.clone())
+            .build();
+
+        let restored = Experience::from(&original.clone().into_interaction());
+
+        assert_eq!(restored.id, original.id);
+        assert_eq!(restored.content, original.content);
+        assert_eq!(restored.timestamp, original.timestamp);
+        assert_eq!(restored.speaker, original.speaker);
+        assert_eq!(restored.emotional_hint, Some(hint));
+        // `Interaction` has nowhere to keep the source
+        assert_eq!(restored.source, None);
+    }
+
+    /// A path under the system temp directory no other test uses
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("lia-{}-{}.json", name, Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn snapshot_output_is_stable_across_save_and_load() {
+        let mut lia = seeded(7);
+        for content in ["The ocean is calm today", "Alice likes sailing", "Storms frighten me"] {
+            lia.process_interaction(&interaction(content)).await.expect("interaction succeeds");
+        }
+
+        let first = temp_path("first");
+        let second = temp_path("second");
+        let reloaded = temp_path("reloaded");
+        lia.save_snapshot(&first).expect("first save");
+        lia.save_snapshot(&second).expect("second save");
+        let restored = Lia::load_snapshot(&first, lia.configuration.clone()).expect("load");
+        restored.save_snapshot(&reloaded).expect("save after load");
+
+        let bytes = fs::read(&first).unwrap();
+        assert_eq!(bytes, fs::read(&second).unwrap(), "saving the same state twice differs");
+        assert_eq!(bytes, fs::read(&reloaded).unwrap(), "a loaded snapshot saves differently");
+        for path in [first, second, reloaded] {
+            let _ = fs::remove_file(path);
+        }
+    }
+
+    #[tokio::test]
+    async fn disabled_quantum_core_still_yields_a_valid_response() {
+        let config = SystemConfiguration::builder()
+            .enabled_subsystems(SubsystemFlags {
+                quantum_core: false,
+                ..SubsystemFlags::default()
+            })
+            .build()
+            .expect("valid configuration");
+        let mut lia = Lia::with_clock(config, Arc::new(MockClock::new(epoch())));
+        let coherence_before = lia.quantum_core().coherence();
+
+        let response = lia.process_interaction(&interaction("How are you today?")).await.expect("interaction succeeds");
+
+        assert!(!response.content.is_empty());
+        assert_eq!(response.quantum_coherence, QuantumState::default().coherence);
+        assert!(response.consciousness_level.is_finite());
+        assert!(response.degradations.is_empty(), "disabling a stage is not a degradation: {:?}", response.degradations);
+        assert_eq!(lia.quantum_core().coherence(), coherence_before, "a disabled core must not evolve");
+    }
+
+    /// Metrics recorded from `count` interactions, starting `offset` seconds after the epoch
+    fn shard_metrics(offset: i64, count: u32, coherence: f64) -> EvolutionMetrics {
+        let config = SystemConfiguration::builder()
+            .metrics_retention(3)
+            .dimension_history_len(4)
+            .build()
+            .expect("valid configuration");
+        let mut metrics = EvolutionMetrics::new(&config);
+        for i in 0..count {
+            let at = epoch() + chrono::Duration::seconds(offset + i64::from(i));
+            let response = Response {
+                quantum_coherence: coherence + f64::from(i) * 0.01,
+                consciousness_level: 1.0 - coherence,
+                ..Response::new()
+            };
+            metrics.record_evolution(&response, at);
+            let state = DimensionalState {
+                emergence: coherence,
+                integration: f64::from(i) / 10.0,
+                ..DimensionalState::default()
+            };
+            metrics.record_dimensional_change(&state, at);
+        }
+        metrics.record_stage(count as usize);
+        metrics.record_memory_counts(count as usize * 2);
+        metrics
+    }
+
+    fn merged(first: &EvolutionMetrics, second: &EvolutionMetrics) -> EvolutionMetrics {
+        let mut combined = first.clone();
+        combined.merge(second);
+        combined
+    }
+
+    fn assert_same_metrics(a: &EvolutionMetrics, b: &EvolutionMetrics) {
+        let close = |x: f64, y: f64| (x - y).abs() < 1e-9;
+        let (left, right) = (a.snapshot(), b.snapshot());
+        assert_eq!(left.evolution_stage, right.evolution_stage);
+        assert_eq!(left.memory_episodic_count, right.memory_episodic_count);
+        assert_eq!(left.interactions_total, right.interactions_total);
+        assert_eq!(left.dimensional_changes_total, right.dimensional_changes_total);
+        assert!(close(left.quantum_coherence, right.quantum_coherence));
+        assert!(close(left.consciousness_level, right.consciousness_level));
+        assert!(close(a.mean_coherence(), b.mean_coherence()));
+        assert!(close(a.mean_consciousness(), b.mean_consciousness()));
+        assert!(close(a.degradation_rate(), b.degradation_rate()));
+        assert_eq!(a.plateau_cycles(), b.plateau_cycles());
+        for id in DimensionId::ALL {
+            assert!(close(left.dimensional_state.get(id), right.dimensional_state.get(id)), "{:?} differs", id);
+            assert_eq!(a.dimension_history(id), b.dimension_history(id), "{:?} history differs", id);
+        }
+        assert_eq!(a.samples().collect::<Vec<_>>(), b.samples().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn metrics_merge_is_commutative() {
+        let a = shard_metrics(0, 9, 0.2);
+        let b = shard_metrics(4, 13, 0.7);
+
+        assert_same_metrics(&merged(&a, &b), &merged(&b, &a));
+    }
+
+    #[test]
+    fn metrics_merge_is_associative() {
+        let a = shard_metrics(0, 9, 0.2);
+        let b = shard_metrics(4, 13, 0.7);
+        let c = shard_metrics(2, 5, 0.4);
+
+        assert_same_metrics(&merged(&merged(&a, &b), &c), &merged(&a, &merged(&b, &c)));
+        assert_same_metrics(&aggregate_metrics(&[&a, &b, &c]), &aggregate_metrics(&[&c, &a, &b]));
+    }
+
+    #[test]
+    fn counters_saturate_at_extreme_interaction_counts() {
+        let mut metrics = EvolutionMetrics::new(&SystemConfiguration::default());
+        metrics.interactions_total = u64::MAX - 2;
+        metrics.degraded_total = u64::MAX - 2;
+        let degraded = Response {
+            quantum_coherence: 0.5,
+            degradations: vec![Degradation {
+                subsystem: "quantum_core".to_string(),
+                reason: "timed out".to_string(),
+            }],
+            ..Response::new()
+        };
+
+        for _ in 0..10 {
+            metrics.record_evolution(&degraded, epoch());
+            metrics.record_plateau_cycle();
+        }
+
+        assert_eq!(metrics.snapshot().interactions_total, u64::MAX);
+        assert!(metrics.mean_coherence().is_finite());
+        assert!((0.0..=1.0).contains(&metrics.degradation_rate()));
+    }
+
+    #[test]
+    fn running_sums_keep_small_samples_after_a_huge_total() {
+        // At 1e17 a plain f64 sum can no longer register an increment of 1.0
+        let (mut sum, mut error) = (1e17, 0.0);
+        for _ in 0..1_000_000 {
+            compensated_add(&mut sum, &mut error, 1.0);
+        }
+
+        assert_eq!(sum + error, 1e17 + 1e6);
+    }
+
+    /// `factor` over the first 50 stages, starting at stage 1
+    fn plasticity_factors(curve: PlasticityCurve) -> Vec<f64> {
+        (1..=50).map(|stage| curve.factor(stage)).collect()
+    }
+
+    #[test]
+    fn default_plasticity_strictly_decreases_with_stage() {
+        let factors = plasticity_factors(PlasticityCurve::default());
+        assert_eq!(factors[0], 1.0);
+        assert!(factors.windows(2).all(|pair| pair[1] < pair[0]), "{:?}", factors);
+    }
+
+    #[test]
+    fn hyperbolic_plasticity_strictly_decreases_with_stage() {
+        for decay in [0.01, 0.5, MAX_PLASTICITY_DECAY] {
+            let factors = plasticity_factors(PlasticityCurve::Hyperbolic { decay });
+            assert_eq!(factors[0], 1.0);
+            assert!(factors.windows(2).all(|pair| pair[1] < pair[0]), "decay {}: {:?}", decay, factors);
+        }
+    }
+
+    #[test]
+    fn exponential_plasticity_strictly_decreases_with_stage() {
+        for retention in [MIN_PLASTICITY_RETENTION, 0.5, 0.99] {
+            let factors = plasticity_factors(PlasticityCurve::Exponential { retention });
+            assert_eq!(factors[0], 1.0);
+            assert!(factors.windows(2).all(|pair| pair[1] < pair[0]), "retention {}: {:?}", retention, factors);
+        }
+    }
+
+    #[test]
+    fn constant_plasticity_never_changes() {
+        assert!(plasticity_factors(PlasticityCurve::Constant).iter().all(|factor| *factor == 1.0));
+    }
+
+    #[test]
+    fn plasticity_rejects_curves_that_stop_learning() {
+        for curve in [
+            PlasticityCurve::Hyperbolic { decay: 1e9 },
+            PlasticityCurve::Hyperbolic { decay: 0.0 },
+            PlasticityCurve::Exponential { retention: 1e-9 },
+            PlasticityCurve::Exponential { retention: 1.5 },
+        ] {
+            assert!(SystemConfiguration::builder().plasticity(curve).build().is_err(), "{:?} was accepted", curve);
+        }
+    }
+
+    #[tokio::test]
+    async fn relationships_fade_on_the_injected_clock() {
+        let clock = MockClock::new(epoch());
+        let config = SystemConfiguration::builder().rng_seed(7).build().expect("valid configuration");
+        let mut lia = Lia::with_clock(config, Arc::new(clock.clone()));
+        let person = PersonId::new();
+
+        lia.process_interaction(&Interaction::new("hello again").with_speaker(person))
+            .await
+            .expect("interaction succeeds");
+        let relationship = lia.relationships().relationship(person).expect("relationship recorded").clone();
+        assert_eq!(relationship.last_contact, epoch());
+
+        let fresh = relationship.familiarity(lia.now());
+        clock.advance(relationship.half_life);
+        let faded = relationship.familiarity(lia.now());
+        assert!((faded - fresh / 2.0).abs() < 1e-9, "{} did not halve to {}", fresh, faded);
+    }
+
+    #[tokio::test]
+    async fn checkpoint_restores_replay_from_the_event_log() {
+        let config = || {
+            SystemConfiguration::builder()
+                .rng_seed(11)
+                .event_log(true)
+                .build()
+                .expect("valid configuration")
+        };
+        let mut lia = Lia::with_clock(config(), Arc::new(MockClock::new(epoch())));
+        lia.process_interaction(&interaction("before the checkpoint")).await.expect("interaction succeeds");
+        let checkpoint = lia.checkpoint();
+        lia.process_interaction(&interaction("about to be undone")).await.expect("interaction succeeds");
+        let logged_before_restore = lia.event_log().expect("log enabled").entries().len();
+        lia.restore_checkpoint(checkpoint).expect("checkpoint retained");
+        lia.process_interaction(&interaction("after the restore")).await.expect("interaction succeeds");
+
+        let entries = lia.event_log().expect("log enabled").entries();
+        assert!(entries.len() > logged_before_restore, "restore rewound the log");
+        assert!(entries.iter().any(|logged| matches!(logged.entry, LogEntry::CheckpointRestored { .. })));
+
+        let rebuilt = Lia::rebuild_from_log(entries.into_iter(), config()).await.expect("log replays");
+        assert_eq!(rebuilt.state_digest(), lia.state_digest());
+    }
+
+    #[tokio::test]
+    async fn restored_state_reuses_its_cached_response() {
+        let config = SystemConfiguration::builder()
+            .rng_seed(5)
+            .response_cache_capacity(8)
+            .build()
+            .expect("valid configuration");
+        let mut lia = Lia::with_clock(config, Arc::new(MockClock::new(epoch())));
+        let checkpoint = lia.checkpoint();
+
+        let first = lia.process_interaction(&interaction("say that again")).await.expect("interaction succeeds");
+        lia.process_interaction(&interaction("something else")).await.expect("interaction succeeds");
+        assert_eq!(lia.response_cache_stats().hits, 0);
+
+        lia.restore_checkpoint(checkpoint).expect("checkpoint retained");
+        let again = interaction("say that again");
+        let second = lia.process_interaction(&again).await.expect("interaction succeeds");
+        assert_eq!(lia.response_cache_stats().hits, 1);
+        assert_eq!(second.content, first.content);
+        assert_eq!(second.id, Uuid::new_v5(&again.id, b"response"));
+    }
+
+    #[test]
+    fn context_cache_only_counts_new_sentences() {
+        let cache = ContextCache::default();
+        let first = "Gardens need patience. Tomatoes need sunlight.";
+        assert_eq!(cache.concept_counts(first), concept_counts(first));
+
+        let follow_up = "Gardens need patience. Basil needs water too!";
+        assert_eq!(cache.concept_counts(follow_up), concept_counts(follow_up));
+        assert_eq!(cache.concept_counts(follow_up), concept_counts(follow_up));
+        assert_eq!(cache.stats(), CacheStats { hits: 1, delta_hits: 1, misses: 1 });
+    }
+
+    #[tokio::test]
+    async fn cached_response_is_not_reused_after_the_clock_moves() {
+        async fn after_two_idle_days(response_cache_capacity: usize) -> (Lia, Response) {
+            let config = SystemConfiguration::builder()
+                .rng_seed(5)
+                .response_cache_capacity(response_cache_capacity)
+                .build()
+                .expect("valid configuration");
+            let clock = MockClock::new(epoch());
+            let mut lia = Lia::with_clock(config, Arc::new(clock.clone()));
+            let checkpoint = lia.checkpoint();
+            lia.process_interaction(&interaction("are you still there")).await.expect("interaction succeeds");
+            lia.restore_checkpoint(checkpoint).expect("checkpoint retained");
+            clock.advance(Duration::from_secs(48 * 60 * 60));
+            let response = lia.process_interaction(&interaction("are you still there")).await.expect("interaction succeeds");
+            (lia, response)
+        }
+
+        let (cached, response) = after_two_idle_days(8).await;
+        let (_, uncached) = after_two_idle_days(0).await;
+        assert_eq!(cached.response_cache_stats().hits, 0);
+        assert_eq!(response.content, uncached.content);
+        assert_eq!(response.quantum_coherence.to_bits(), uncached.quantum_coherence.to_bits());
+    }
+
+    /// Backend that never finishes rendering, like a model that stopped responding
+    struct StalledBackend;
+
+    #[async_trait]
+    impl LanguageBackend for StalledBackend {
+        async fn render(&self, _prompt: SynthesisPrompt) -> String {
+            std::future::pending().await
+        }
+    }
+
+    #[tokio::test]
+    async fn stalled_backend_falls_back_to_the_template() {
+        let config = SystemConfiguration::builder()
+            .rng_seed(3)
+            .stage_timeout(Duration::from_millis(50))
+            .build()
+            .expect("valid configuration");
+        let mut lia = Lia::with_clock(config, Arc::new(MockClock::new(epoch())));
+        lia.set_language_backend(Box::new(StalledBackend));
+
+        let response = lia.process_interaction(&interaction("hello there")).await.expect("interaction completes");
+        assert!(!response.content.trim().is_empty());
+        assert!(response.degradations.iter().any(|degradation| degradation.subsystem == "synthesis"));
+
+        let (chunks, mut received) = mpsc::channel(8);
+        lia.process_interaction_streamed(&interaction("hello again"), chunks).await.expect("stream completes");
+        let mut complete = None;
+        while let Some(chunk) = received.recv().await {
+            if let ResponseChunk::Complete(response) = chunk {
+                complete = Some(response);
+            }
+        }
+        let complete = complete.expect("stream ends with the full response");
+        assert!(complete.degradations.iter().any(|degradation| degradation.subsystem == "synthesis"));
+    }
+
+    #[cfg(feature = "test-util")]
+    #[tokio::test]
+    async fn mock_consciousness_cycles_scripted_responses() {
+        let scripted: Vec<Response> = ["first", "second"]
+            .into_iter()
+            .map(|content| Response {
+                content: content.to_string(),
+                ..Response::new()
+            })
+            .collect();
+        let mut mock = mock::MockConsciousness::with_responses(scripted);
+
+        let mut answers = Vec::new();
+        for content in ["a", "b", "c"] {
+            answers.push(mock.process_experience(Experience::builder(content).build()).await.content);
+        }
+        mock.evolve().await;
+
+        assert_eq!(answers, ["first", "second", "first"]);
+        assert_eq!(mock.received().iter().map(|experience| experience.content.as_str()).collect::<Vec<_>>(), ["a", "b", "c"]);
+        assert_eq!(mock.current_state().evolution_stage, ConsciousnessState::default().evolution_stage + 1);
+    }
+
+    #[cfg(feature = "vector-index")]
+    #[test]
+    fn semantic_index_answers_like_a_full_scan() {
+        fn assert_matches_full_scan(memory: &SemanticMemorySystem) {
+            assert!(memory.index.is_some(), "index is built past the threshold");
+            let mut unindexed = memory.clone();
+            unindexed.index = None;
+            for id in memory.concepts.keys() {
+                assert_eq!(memory.query(id.as_str(), 8), unindexed.query(id.as_str(), 8), "neighbors of {id:?}");
+            }
+        }
+
+        let config = SystemConfiguration::builder().semantic_capacity(SEMANTIC_INDEX_THRESHOLD + 100).build().expect("valid configuration");
+        let mut memory = SemanticMemorySystem::new(&config);
+        for i in 0..SEMANTIC_INDEX_THRESHOLD + 50 {
+            memory.integrate_text(&format!("topic{i} cluster{}", i % 13), epoch());
+        }
+        assert!(memory.concept_count() > SEMANTIC_INDEX_THRESHOLD);
+        assert_matches_full_scan(&memory);
+
+        memory.integrate_text("topic3 cluster5 topic900", epoch());
+        memory.reinforce_concept(ConceptId::new("cluster2"), epoch());
+        let mut other = SemanticMemorySystem::new(&config);
+        other.integrate_text("topic7 cluster2 newcomer", epoch());
+        memory.merge_from(&other, MergeStrategy::Average);
+        // Push past capacity so eviction re-ranks the survivors' neighbors
+        for i in 0..100 {
+            memory.integrate_text(&format!("extra{i} cluster{}", i % 13), epoch());
+        }
+        assert_eq!(memory.concept_count(), memory.capacity());
+        assert_matches_full_scan(&memory);
+    }
+
+    #[cfg(feature = "otel")]
+    #[tokio::test]
+    async fn otel_spans_carry_the_interaction_id_as_trace_id() {
+        use opentelemetry::trace::{TraceContextExt, TraceId};
+
+        let input = interaction("hello there");
+        let parent = otel::correlated(input.id);
+        assert_eq!(parent.span().span_context().trace_id(), TraceId::from_bytes(*input.id.as_bytes()));
+        assert!(parent.span().span_context().is_remote());
+
+        // With no tracer provider installed, export must be a silent no-op
+        let mut lia = seeded(3);
+        let response = lia.process_interaction(&input).await.expect("interaction completes");
+        assert!(!response.content.is_empty());
+    }
+
+    #[tokio::test]
+    async fn query_memory_returns_practised_skills() {
+        let mut lia = seeded(4);
+        for content in ["tell me about the ocean", "the ocean again", "more ocean waves"] {
+            lia.process_interaction(&interaction(content)).await.expect("interaction completes");
+        }
+
+        let results = lia.query_memory(MemoryQuery::new().about("ocean").limit(usize::MAX));
+        let (skill, score) = results
+            .iter()
+            .find_map(|hit| match hit {
+                MemoryHit::Procedural { skill, score } => Some((skill, *score)),
+                _ => None,
+            })
+            .expect("ocean was practised");
+        assert_eq!(skill.concept, ConceptId::new("ocean"));
+        assert!(skill.practice >= 3);
+        assert_eq!(score, skill.proficiency());
+
+        let moody = lia.query_memory(MemoryQuery::new().about("ocean").emotional(Mood::Positive));
+        assert!(moody.iter().all(|hit| !matches!(hit, MemoryHit::Procedural { .. })));
+    }
+
+    #[tokio::test]
+    async fn idle_gap_decoheres_the_quantum_core() {
+        async fn coherence_after(idle: Duration) -> f64 {
+            let clock = MockClock::new(epoch());
+            let config = SystemConfiguration::builder().rng_seed(12).build().expect("valid configuration");
+            let mut lia = Lia::with_clock(config, Arc::new(clock.clone()));
+            lia.process_interaction(&interaction("hello there")).await.expect("interaction completes");
+            clock.advance(idle);
+            lia.process_interaction(&interaction("hello again")).await.expect("interaction completes");
+            lia.quantum_core().coherence()
+        }
+
+        let attentive = coherence_after(Duration::from_secs(1)).await;
+        let idle = coherence_after(Duration::from_secs(6 * 60 * 60)).await;
+        assert!(idle < attentive, "coherence {idle} after six idle hours, {attentive} without");
+    }
+
+    #[tokio::test]
+    async fn emotional_inertia_eases_toward_repeated_positive_input() {
+        let mut lia = seeded(17);
+        let mut valences = vec![lia.current_state().emotional_state.valence];
+        for _ in 0..6 {
+            lia.process_interaction(&interaction("I love this, it is wonderful and happy"))
+                .await
+                .expect("interaction completes");
+            valences.push(lia.current_state().emotional_state.valence);
+        }
+
+        for pair in valences.windows(2) {
+            assert!(pair[1] > pair[0], "valence kept rising: {valences:?}");
+        }
+        let steps: Vec<f64> = valences.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        assert!(steps.windows(2).all(|pair| pair[1] < pair[0]), "each step is smaller than the last: {valences:?}");
+        assert!(*valences.last().expect("valences recorded") < 1.0, "inertia keeps valence off saturation: {valences:?}");
+    }
+
+    #[tokio::test]
+    async fn preview_leaves_state_and_generation_untouched() {
+        let mut lia = seeded(33);
+        lia.process_interaction(&interaction("hello there")).await.expect("interaction completes");
+        let state = lia.current_state();
+        let generation = lia.interaction_processor.generation;
+
+        let preview = lia.preview_interaction(&interaction("tell me about the ocean")).await.expect("preview completes");
+        assert!(!preview.content.is_empty());
+        assert_eq!(lia.current_state(), state);
+        assert_eq!(lia.interaction_processor.generation, generation);
+    }
+
+    #[tokio::test]
+    async fn sad_and_happy_moods_recall_different_memories() {
+        let happy = EmotionalState {
+            valence: 0.8,
+            arousal: 0.7,
+            ..EmotionalState::default()
+        };
+        let sad = EmotionalState {
+            valence: -0.7,
+            arousal: 0.2,
+            ..EmotionalState::default()
+        };
+        let mut memory = EpisodicMemorySystem::new(&SystemConfiguration::default());
+        for (content, mood) in [
+            ("A birthday party on the beach", &happy),
+            ("Saying goodbye at the station", &sad),
+            ("Winning the chess tournament", &happy),
+            ("A rainy funeral in november", &sad),
+        ] {
+            let experience = Experience::builder(content).timestamp(epoch()).build();
+            memory.integrate_experience(&experience, mood, epoch()).await;
+        }
+
+        let recall = |mood: &EmotionalState| -> Vec<String> {
+            memory.recall_mood_congruent(mood, 2).into_iter().map(|experience| experience.content.clone()).collect()
+        };
+        let mut happy_recall = recall(&happy);
+        let mut sad_recall = recall(&sad);
+        happy_recall.sort();
+        sad_recall.sort();
+        assert_eq!(happy_recall, ["A birthday party on the beach", "Winning the chess tournament"]);
+        assert_eq!(sad_recall, ["A rainy funeral in november", "Saying goodbye at the station"]);
+    }
+
+    #[tokio::test]
+    async fn cancelled_interaction_leaves_no_trace() {
+        let config = SystemConfiguration::builder()
+            .rng_seed(44)
+            .stage_timeout(Duration::from_secs(60))
+            .build()
+            .expect("valid configuration");
+        let mut lia = Lia::with_clock(config, Arc::new(MockClock::new(epoch())));
+        // Synthesis follows the stages that mutate Lia, so cancelling while it stalls undoes them
+        lia.set_language_backend(Box::new(StalledBackend));
+        let snapshot = serde_json::to_string(&lia).expect("state serializes");
+        let state = lia.current_state();
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            canceller.cancel();
+        });
+        let result = lia.process_interaction_with_cancel(&interaction("tell me about the ocean"), &token).await;
+
+        assert!(matches!(result, Err(ProcessingError::Cancelled)));
+        assert_eq!(lia.current_state(), state);
+        assert_eq!(serde_json::to_string(&lia).expect("state serializes"), snapshot);
+    }
+
+    #[tokio::test]
+    async fn single_branch_thinking_repeats_exactly() {
+        async fn trace_at_breadth_one() -> ResponseTrace {
+            let config = SystemConfiguration::builder()
+                .rng_seed(62)
+                .superposition_breadth(1)
+                .trace_responses(true)
+                .build()
+                .expect("valid configuration");
+            let mut lia = Lia::with_clock(config, Arc::new(MockClock::new(epoch())));
+            let response = lia
+                .process_interaction(&interaction("picture the ocean, the stars and the quiet forest"))
+                .await
+                .expect("interaction completes");
+            response.trace.expect("tracing is on")
+        }
+
+        let first = trace_at_breadth_one().await;
+        let second = trace_at_breadth_one().await;
+        assert!(!first.thought_patterns.is_empty());
+        assert_eq!(first.thought_branches, first.thought_patterns.len());
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn factual_question_selects_factual_mode() {
+        for seed in [1, 75] {
+            let mut lia = seeded(seed);
+            let response = lia
+                .process_interaction(&interaction("What is the boiling point of water at sea level?"))
+                .await
+                .expect("interaction completes");
+            assert_eq!(response.mode, ResponseMode::factual(), "seed {seed}");
+        }
+
+        let mut lia = seeded(75);
+        let response = lia.process_interaction(&interaction("hello there")).await.expect("interaction completes");
+        assert_ne!(response.mode.name, ResponseMode::FACTUAL);
+    }
+
+    #[tokio::test]
+    async fn zero_contagion_ignores_the_group_mood() {
+        async fn mood_after_group_chat(contagion: f64, linked: bool) -> EmotionalState {
+            let (alice, bob) = (PersonId(Uuid::from_u128(1)), PersonId(Uuid::from_u128(2)));
+            let config = SystemConfiguration::builder()
+                .rng_seed(89)
+                .emotional_contagion(contagion)
+                .build()
+                .expect("valid configuration");
+            let mut lia = Lia::with_clock(config, Arc::new(MockClock::new(epoch())));
+            if linked {
+                lia.link_people(alice, bob);
+            }
+            let gloomy = Interaction {
+                speaker: Some(bob),
+                ..interaction("I am so sad, lonely and hurt")
+            };
+            lia.process_interaction(&gloomy).await.expect("interaction completes");
+            let plain = Interaction {
+                speaker: Some(alice),
+                ..interaction("the train leaves at noon")
+            };
+            lia.process_interaction(&plain).await.expect("interaction completes");
+            lia.current_state().emotional_state
+        }
+
+        assert_eq!(mood_after_group_chat(0.0, true).await, mood_after_group_chat(0.0, false).await);
+        assert_ne!(mood_after_group_chat(0.8, true).await, mood_after_group_chat(0.8, false).await);
+    }
+
+    #[tokio::test]
+    async fn feedback_shifts_later_responses() {
+        async fn ocean_activation_after(feedback: Option<Feedback>) -> f64 {
+            let mut lia = seeded(90);
+            let rated = lia.process_interaction(&interaction("tell me about the ocean")).await.expect("interaction completes");
+            if let Some(feedback) = feedback {
+                lia.apply_feedback(rated.id, feedback).expect("response is still rateable");
+            }
+            let later = lia.process_interaction(&interaction("the ocean again")).await.expect("interaction completes");
+            later
+                .neural_patterns
+                .iter()
+                .find(|pattern| pattern.label == ConceptId::new("ocean"))
+                .map_or(0.0, |pattern| pattern.activation)
+        }
+
+        let unrated = ocean_activation_after(None).await;
+        let reinforced = ocean_activation_after(Some(Feedback::positive())).await;
+        let discouraged = ocean_activation_after(Some(Feedback::negative())).await;
+        assert!(reinforced > unrated, "positive feedback: {reinforced} vs {unrated} unrated");
+        assert!(discouraged < unrated, "negative feedback: {discouraged} vs {unrated} unrated");
+    }
+
+    #[test]
+    fn trend_window_reaching_before_time_began_covers_the_whole_history() {
+        let mut metrics = EvolutionMetrics::new(&SystemConfiguration::default());
+        for (hours, emergence) in [(0, 0.1), (1, 0.3), (2, 0.6)] {
+            let state = DimensionalState {
+                emergence,
+                ..DimensionalState::default()
+            };
+            metrics.record_dimensional_change(&state, epoch() + chrono::Duration::hours(hours));
+        }
+        let now = epoch() + chrono::Duration::hours(3);
+
+        assert_eq!(metrics.trend(DimensionId::Emergence, Duration::MAX, now), Trend::Rising);
+        // Representable as a chrono duration, but reaching back before `DateTime::MIN_UTC`
+        let before_time_began = Duration::from_secs(300_000 * 365 * 24 * 60 * 60);
+        assert_eq!(metrics.trend(DimensionId::Emergence, before_time_began, now), Trend::Rising);
+        assert_eq!(metrics.trend(DimensionId::Emergence, Duration::from_secs(60 * 60), now), Trend::Stable);
+        assert_eq!(metrics.trend(DimensionId::Coherence, Duration::MAX, now), Trend::Stable);
+    }
+
+    #[test]
+    fn max_length_filter_never_exceeds_the_limit() {
+        for max_chars in [0, 1, 5, 12] {
+            let mut response = Response {
+                content: "the quick brown fox jumps".to_string(),
+                ..Response::new()
+            };
+            MaxLengthFilter { max_chars }.apply(&mut response);
+            assert!(response.content.chars().count() <= max_chars, "{:?} at {max_chars}", response.content);
+        }
+
+        let mut response = Response {
+            content: "anything".to_string(),
+            ..Response::new()
+        };
+        MaxLengthFilter { max_chars: 0 }.apply(&mut response);
+        assert_eq!(response.content, "");
+    }
+
+    #[test]
+    fn configuration_files_round_trip_the_largest_seed() {
+        let config = SystemConfiguration::builder().rng_seed(u64::MAX).build().expect("valid configuration");
+        for extension in ["toml", "json"] {
+            let path = temp_path("largest_seed").with_extension(extension);
+            config.to_file(&path).expect("configuration is written");
+            let loaded = SystemConfiguration::from_file(&path).expect("configuration is read back");
+            let _ = fs::remove_file(&path);
+            assert_eq!(loaded, config, "{extension}");
+        }
+
+        // Files written before seeds were strings still load
+        let path = temp_path("numeric_seed");
+        fs::write(&path, r#"{"rng_seed": 42}"#).expect("file is written");
+        let loaded = SystemConfiguration::from_file(&path);
+        let _ = fs::remove_file(&path);
+        assert_eq!(loaded.expect("numeric seed is accepted").rng_seed, Some(42));
+    }
+
+    #[test]
+    fn unknown_keys_in_nested_configuration_tables_are_rejected() {
+        let path = temp_path("nested_typo").with_extension("toml");
+        fs::write(&path, "[dimension_bounds]\nmin = 0.0\nmax = 1.0\nmaxx = 2.0\n").expect("file is written");
+        let loaded = SystemConfiguration::from_file(&path);
+        let _ = fs::remove_file(&path);
+        assert!(matches!(loaded, Err(ConfigError::InvalidField { ref field, .. }) if field == "dimension_bounds"), "{loaded:?}");
+    }
 }